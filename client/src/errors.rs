@@ -0,0 +1,46 @@
+use core::fmt;
+
+pub use nfts_extension_types::errors::{NftsError, NftsErrorCategory, SUCCESS};
+
+/// Errors of the decoding of an extension call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The function id isn't one of a function of the extension.
+    UnknownFunction(u32),
+    /// The runtime returned an error status code.
+    Status(NftsError),
+    /// The bytes aren't the encoding of the input or output of the function.
+    Codec(codec::Error),
+}
+
+impl From<NftsError> for Error {
+    fn from(error: NftsError) -> Self {
+        Self::Status(error)
+    }
+}
+
+impl From<codec::Error> for Error {
+    fn from(error: codec::Error) -> Self {
+        Self::Codec(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFunction(func_id) => write!(f, "unknown function id {:#010x}", func_id),
+            Self::Status(error) => write!(f, "extension call failed: {}", error),
+            Self::Codec(error) => write!(f, "invalid SCALE encoding: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Status(error) => Some(error),
+            Self::Codec(error) => Some(error),
+            Self::UnknownFunction(_) => None,
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Inputs and outputs of the functions of the extension.
+//!
+//! Each function is a type implementing [`Function`], with the types the runtime reads as input
+//! and writes as output. [`Call`] and [`Output`] gather them for the calls only known at runtime,
+//! such as the ones of a contract trace.
+
+use crate::errors::{Error, NftsError};
+use crate::{AccountId, Config, DefaultConfig, FuncId};
+use codec::{Decode, DecodeAll, Encode};
+use core::marker::PhantomData;
+use nfts_extension_types::ids::{dry_run_func_id, func_id, function, VERSION};
+use nfts_extension_types::{
+    AttributeNamespaceExt, BatchModeExt, CapabilitiesExt, CollectionConfigExt,
+    CollectionDetailsExt, CreateInput, ItemSettingsExt, MintWitnessExt, NftsConstantsExt, Page,
+    WeightExt,
+};
+
+/// A function of the extension.
+pub trait Function {
+    /// Function byte of the function id, one of the `ids::function` constants.
+    const FUNCTION: u8;
+    /// Whether the runtime writes the output as a `Result`, as the functions dispatching a call of
+    /// the pallet and `get_attribute` do. The other functions write the value alone.
+    const RESULT: bool;
+
+    /// Input read by the runtime.
+    type Input: Encode + Decode;
+    /// Output written by the runtime on success, without the `Result`.
+    type Output: Decode;
+
+    /// Id of the function in the current [`VERSION`].
+    fn func_id() -> u32 {
+        func_id(VERSION, Self::FUNCTION)
+    }
+
+    /// Id of the dry run of the function in the current [`VERSION`].
+    fn dry_run_func_id() -> u32 {
+        dry_run_func_id(VERSION, Self::FUNCTION)
+    }
+
+    /// Input buffer of a call of the function.
+    fn encode_input(input: &Self::Input) -> Vec<u8> {
+        input.encode()
+    }
+
+    /// Decode the input buffer of a call of the function.
+    fn decode_input(input: &[u8]) -> Result<Self::Input, Error> {
+        Ok(Self::Input::decode_all(&mut &input[..])?)
+    }
+
+    /// Decode the status code and the output buffer of a call of the function. A function
+    /// returning `Result<(), NftsError>` writes no output, an empty buffer is its success.
+    fn decode_output(status: u32, output: &[u8]) -> Result<Self::Output, Error> {
+        NftsError::from_status_code(status)?;
+        if Self::RESULT && !output.is_empty() {
+            let result = Result::<Self::Output, NftsError>::decode_all(&mut &output[..])?;
+            Ok(result?)
+        } else {
+            Ok(Self::Output::decode_all(&mut &output[..])?)
+        }
+    }
+
+    /// Decode the status code and the output buffer of a dry run of the function: the result the
+    /// function would have returned, as by [`Function::decode_output`], and the weight it consumed.
+    fn decode_dry_run(
+        status: u32,
+        output: &[u8],
+    ) -> Result<(Result<Self::Output, Error>, WeightExt), Error> {
+        let dry_run = crate::decode_dry_run(status, output)?;
+        let result = Self::decode_output(dry_run.status, &dry_run.output);
+        Ok((result, dry_run.weight))
+    }
+}
+
+macro_rules! functions {
+    (@result result) => { true };
+    (@result value) => { false };
+    ($(
+        $(#[$doc:meta])*
+        $name:ident($function:ident, $kind:ident): $input:ty => $output:ty;
+    )*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct $name<C = DefaultConfig>(PhantomData<C>);
+
+            impl<C: Config> Function for $name<C> {
+                const FUNCTION: u8 = function::$function;
+                const RESULT: bool = functions!(@result $kind);
+
+                type Input = $input;
+                type Output = $output;
+            }
+        )*
+
+        /// Call of a function of the extension, with its input.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum Call<C: Config = DefaultConfig> {
+            $(
+                $(#[$doc])*
+                $name($input),
+            )*
+        }
+
+        /// Output of a successful call of a function of the extension.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum Output<C: Config = DefaultConfig> {
+            $(
+                $(#[$doc])*
+                $name($output),
+            )*
+        }
+
+        impl<C: Config> Call<C> {
+            /// Decode the input buffer of a call of the function `func_id`. The version and the
+            /// dry run flag of the id don't change the input.
+            pub fn decode(func_id: u32, input: &[u8]) -> Result<Self, Error> {
+                match FuncId::parse(func_id)?.function {
+                    $(
+                        function::$function => {
+                            Ok(Call::$name($name::<C>::decode_input(input)?))
+                        }
+                    )*
+                    _ => Err(Error::UnknownFunction(func_id)),
+                }
+            }
+
+            /// Function byte of the call.
+            pub fn function(&self) -> u8 {
+                match self {
+                    $(Call::$name(_) => function::$function,)*
+                }
+            }
+
+            /// Id of the call in the current [`VERSION`].
+            pub fn func_id(&self) -> u32 {
+                func_id(VERSION, self.function())
+            }
+
+            /// Input buffer of the call.
+            pub fn encode_input(&self) -> Vec<u8> {
+                match self {
+                    $(Call::$name(input) => $name::<C>::encode_input(input),)*
+                }
+            }
+
+            /// Decode the status code and the output buffer of the call, see
+            /// [`Function::decode_output`]. The output of a dry run is decoded by
+            /// [`Function::decode_dry_run`] instead.
+            pub fn decode_output(&self, status: u32, output: &[u8]) -> Result<Output<C>, Error> {
+                match self {
+                    $(
+                        Call::$name(_) => {
+                            Ok(Output::$name($name::<C>::decode_output(status, output)?))
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+functions! {
+    // Extrinsics
+    /// `create`, returns the id of the new collection.
+    Create(CREATE, result): CreateInput<AccountId, C::Balance, C::BlockNumber, C::CollectionId>
+        => C::CollectionId;
+    /// `redeposit` of items of a collection.
+    Redeposit(REDEPOSIT, result): (C::CollectionId, Vec<C::ItemId>) => ();
+    // Privileged extrinsics
+    /// `force_create`, returns the id of the new collection.
+    ForceCreate(FORCE_CREATE, result):
+        (AccountId, CollectionConfigExt<C::Balance, C::BlockNumber, C::CollectionId>)
+        => C::CollectionId;
+    /// `force_mint` of an item with the given settings.
+    ForceMint(FORCE_MINT, result): (C::CollectionId, C::ItemId, AccountId, ItemSettingsExt) => ();
+    /// `force_collection_owner`.
+    ForceCollectionOwner(FORCE_COLLECTION_OWNER, result): (C::CollectionId, AccountId) => ();
+    /// `force_collection_config`.
+    ForceCollectionConfig(FORCE_COLLECTION_CONFIG, result):
+        (C::CollectionId, CollectionConfigExt<C::Balance, C::BlockNumber, C::CollectionId>) => ();
+    /// `force_set_attribute`: the account set as, the collection, the item, the namespace, the
+    /// key and the value.
+    ForceSetAttribute(FORCE_SET_ATTRIBUTE, result): (
+        Option<AccountId>,
+        C::CollectionId,
+        Option<C::ItemId>,
+        AttributeNamespaceExt<AccountId>,
+        Vec<u8>,
+        Vec<u8>,
+    ) => ();
+    // Chain state
+    /// `get_collection`, the details of a collection.
+    GetCollection(GET_COLLECTION, value): C::CollectionId
+        => Option<CollectionDetailsExt<AccountId, C::Balance>>;
+    /// `get_owned_items`: the owner, the collection, the cursor and the page size.
+    GetOwnedItems(GET_OWNED_ITEMS, value):
+        (AccountId, Option<C::CollectionId>, Option<(C::CollectionId, C::ItemId)>, u32)
+        => Page<(C::CollectionId, C::ItemId), (C::CollectionId, C::ItemId)>;
+    /// `get_collections`: the cursor and the page size.
+    GetCollections(GET_COLLECTIONS, value): (Option<C::CollectionId>, u32)
+        => Page<C::CollectionId, C::CollectionId>;
+    /// `get_owned_collections`: the owner, the cursor and the page size.
+    GetOwnedCollections(GET_OWNED_COLLECTIONS, value): (AccountId, Option<C::CollectionId>, u32)
+        => Page<C::CollectionId, C::CollectionId>;
+    /// `get_collection_config`.
+    GetCollectionConfig(GET_COLLECTION_CONFIG, value): C::CollectionId
+        => Option<CollectionConfigExt<C::Balance, C::BlockNumber, C::CollectionId>>;
+    /// `get_item_config`, the settings of an item.
+    GetItemConfig(GET_ITEM_CONFIG, value): (C::CollectionId, C::ItemId)
+        => Option<ItemSettingsExt>;
+    /// `get_capabilities` of the extension of the runtime.
+    GetCapabilities(GET_CAPABILITIES, value): () => CapabilitiesExt;
+    // Batches
+    /// `batch_mint`, returns the status code of every mint.
+    BatchMint(BATCH_MINT, result): (
+        C::CollectionId,
+        Vec<(C::ItemId, AccountId, Option<MintWitnessExt<C::ItemId>>)>,
+        BatchModeExt,
+    ) => Vec<u32>;
+    /// `batch_transfer`, returns the status code of every transfer.
+    BatchTransfer(BATCH_TRANSFER, result):
+        (Vec<(C::CollectionId, C::ItemId, AccountId)>, BatchModeExt) => Vec<u32>;
+    // Items
+    /// `transfer` of an item to an account.
+    Transfer(TRANSFER, result): (C::CollectionId, C::ItemId, AccountId) => ();
+    /// `approve_transfer` of an item to a delegate, with an optional deadline.
+    ApproveTransfer(APPROVE_TRANSFER, result):
+        (C::CollectionId, C::ItemId, AccountId, Option<C::BlockNumber>) => ();
+    /// `cancel_approval` of a delegate of an item.
+    CancelApproval(CANCEL_APPROVAL, result): (C::CollectionId, C::ItemId, AccountId) => ();
+    /// `get_item_owner`.
+    GetItemOwner(GET_ITEM_OWNER, value): (C::CollectionId, C::ItemId) => Option<AccountId>;
+    /// `get_balance`, the number of items of a collection owned by an account.
+    GetBalance(GET_BALANCE, value): (C::CollectionId, AccountId) => u32;
+    /// `get_approval` of a delegate of an item: `None` when it isn't approved, `Some(None)` for
+    /// an approval without deadline.
+    GetApproval(GET_APPROVAL, value): (C::CollectionId, C::ItemId, AccountId)
+        => Option<Option<C::BlockNumber>>;
+    /// `get_attribute`: the collection, the item, the namespace and the key.
+    GetAttribute(GET_ATTRIBUTE, result):
+        (C::CollectionId, Option<C::ItemId>, AttributeNamespaceExt<AccountId>, Vec<u8>)
+        => Option<Vec<u8>>;
+    // Constants
+    /// `get_approvals_limit`.
+    GetApprovalsLimit(GET_APPROVALS_LIMIT, value): () => u32;
+    /// `get_attribute_deposit_base`.
+    GetAttributeDepositBase(GET_ATTRIBUTE_DEPOSIT_BASE, value): () => C::Balance;
+    /// `get_collection_deposit`.
+    GetCollectionDeposit(GET_COLLECTION_DEPOSIT, value): () => C::Balance;
+    /// `get_deposit_per_byte`.
+    GetDepositPerByte(GET_DEPOSIT_PER_BYTE, value): () => C::Balance;
+    /// `get_nfts_constants`, all the constants of the pallet.
+    GetNftsConstants(GET_NFTS_CONSTANTS, value): ()
+        => NftsConstantsExt<C::Balance, C::BlockNumber>;
+}
@@ -0,0 +1,118 @@
+//! Off-chain client of the NFTs chain extension.
+//!
+//! Encodes the input buffers the runtime reads and decodes the status codes and output buffers it
+//! returns, with the types of [`nfts_extension_types`] and without ink, e.g. for the indexers and
+//! tools reading the extension calls of contract traces. The [`vectors`] are the bytes exchanged
+//! with the runtime for sample calls.
+//!
+//! ```
+//! use nfts_extension_client::functions::{Call, Function, Output, Transfer};
+//! use nfts_extension_client::{DefaultConfig, Error, NftsError};
+//!
+//! // What a contract passes to `seal_call_chain_extension` to transfer the item 42 of the
+//! // collection 3.
+//! let func_id = Transfer::<DefaultConfig>::func_id();
+//! let input = Transfer::<DefaultConfig>::encode_input(&(3, 42, [2; 32]));
+//!
+//! // What an indexer reads back from the trace of the call.
+//! let call = Call::<DefaultConfig>::decode(func_id, &input).unwrap();
+//! assert_eq!(call, Call::Transfer((3, 42, [2; 32])));
+//! assert_eq!(call.decode_output(0, &[]), Ok(Output::Transfer(())));
+//! assert_eq!(
+//!     call.decode_output(2, &[]),
+//!     Err(Error::Status(NftsError::NoPermission))
+//! );
+//! ```
+
+pub mod errors;
+pub mod functions;
+pub mod vectors;
+
+pub use errors::{Error, NftsError};
+
+use codec::{Codec, DecodeAll};
+use core::fmt::Debug;
+use nfts_extension_types::ids::{self, EXTENSION_ID, LEGACY_VERSION};
+use nfts_extension_types::DryRunResultExt;
+
+/// Accounts, as read and written by the extension.
+pub type AccountId = [u8; 32];
+
+/// Types configured by the runtime, they must be the ones of its `pallet_nfts::Config` for the
+/// bytes to match.
+pub trait Config {
+    /// `pallet_nfts::Config::CollectionId`.
+    type CollectionId: Codec + Clone + Debug + PartialEq + Eq;
+    /// `pallet_nfts::Config::ItemId`.
+    type ItemId: Codec + Clone + Debug + PartialEq + Eq;
+    /// Balance of the currency of the pallet.
+    type Balance: Codec + Clone + Debug + PartialEq + Eq;
+    /// `frame_system::Config::BlockNumber`.
+    type BlockNumber: Codec + Clone + Debug + PartialEq + Eq;
+}
+
+/// Types of the usual runtimes, and of the `DefaultEnvironment` of the contracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultConfig {}
+
+impl Config for DefaultConfig {
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Balance = u128;
+    type BlockNumber = u32;
+}
+
+/// Function id of a call, split in its parts. See [`ids`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuncId {
+    /// Version of the interface the contract was built against.
+    pub version: u8,
+    /// Function, one of the `ids::function` constants.
+    pub function: u8,
+    /// Whether the call is a dry run, its output is then decoded by [`decode_dry_run`].
+    pub dry_run: bool,
+}
+
+impl FuncId {
+    /// Split a function id of the NFTs extension. The ids of the [`LEGACY_VERSION`] have no
+    /// extension id and are only the ones of the [`ids::LEGACY_FUNCTIONS`].
+    pub fn parse(func_id: u32) -> Result<Self, Error> {
+        let (low, dry_run) = ids::strip_dry_run(func_id as u16);
+        let (version, function) = ids::split_func_id(low);
+        match (func_id >> 16) as u16 {
+            EXTENSION_ID => Ok(Self {
+                version,
+                function,
+                dry_run,
+            }),
+            0 if version == LEGACY_VERSION
+                && !dry_run
+                && ids::LEGACY_FUNCTIONS.contains(&function) =>
+            {
+                Ok(Self {
+                    version,
+                    function,
+                    dry_run,
+                })
+            }
+            _ => Err(Error::UnknownFunction(func_id)),
+        }
+    }
+}
+
+impl From<FuncId> for u32 {
+    fn from(id: FuncId) -> Self {
+        match (id.version, id.dry_run) {
+            (LEGACY_VERSION, false) => id.function as u32,
+            (version, false) => ids::func_id(version, id.function),
+            (version, true) => ids::dry_run_func_id(version, id.function),
+        }
+    }
+}
+
+/// Decode the status code and the output buffer of a dry run, whatever its function. The status
+/// of the dry run function is in the result.
+pub fn decode_dry_run(status: u32, output: &[u8]) -> Result<DryRunResultExt, Error> {
+    NftsError::from_status_code(status)?;
+    Ok(DryRunResultExt::decode_all(&mut &output[..])?)
+}
@@ -0,0 +1,144 @@
+//! Golden vectors of the extension: the bytes read and written by the runtime for sample calls,
+//! with the types of [`DefaultConfig`](crate::DefaultConfig). They pin the encoding shared by the
+//! runtime, the contracts and this client, any change of one of them is a breaking change of the
+//! interface.
+//!
+//! The vectors are generated by running the sample calls against the extension in a mock runtime,
+//! see the `vectors_match_the_runtime` test of `nfts-extension-impls-runtime`.
+//!
+//! The sample accounts are [`ALICE`] and [`BOB`], the sample collection is `3`.
+//!
+//! ```
+//! use nfts_extension_client::functions::*;
+//! use nfts_extension_client::vectors::{self, ALICE, BOB};
+//! use nfts_extension_client::{decode_dry_run, DefaultConfig, Error, NftsError};
+//! use nfts_extension_types::*;
+//!
+//! fn check<F: Function>(vector: &vectors::Vector, input: F::Input) {
+//!     assert_eq!(F::func_id(), vector.func_id);
+//!     assert_eq!(F::encode_input(&input), vector.input());
+//! }
+//!
+//! let v = &vectors::CREATE;
+//! let input = CreateInput { admin: ALICE, config: CollectionConfigExt::default() };
+//! check::<Create>(v, input);
+//! assert_eq!(Create::<DefaultConfig>::decode_output(v.status, &v.output()), Ok(7));
+//!
+//! let v = &vectors::TRANSFER;
+//! check::<Transfer>(v, (3, 42, BOB));
+//! assert_eq!(
+//!     Transfer::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Err(Error::Status(NftsError::NoPermission))
+//! );
+//!
+//! let v = &vectors::APPROVE_TRANSFER;
+//! check::<ApproveTransfer>(v, (3, 42, BOB, Some(100)));
+//! assert_eq!(ApproveTransfer::<DefaultConfig>::decode_output(v.status, &v.output()), Ok(()));
+//!
+//! let v = &vectors::BATCH_TRANSFER;
+//! check::<BatchTransfer>(v, (vec![(3, 42, BOB), (3, 44, ALICE)], BatchModeExt::BestEffort));
+//! assert_eq!(
+//!     BatchTransfer::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Ok(vec![0, NftsError::UnknownItem.status_code()])
+//! );
+//!
+//! let v = &vectors::FORCE_SET_ATTRIBUTE;
+//! let input = (None, 3, None, AttributeNamespaceExt::Pallet, b"k".to_vec(), b"v".to_vec());
+//! check::<ForceSetAttribute>(v, input);
+//! assert_eq!(
+//!     ForceSetAttribute::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Err(Error::Status(NftsError::NoPermission))
+//! );
+//!
+//! let v = &vectors::GET_COLLECTION;
+//! check::<GetCollection>(v, 3);
+//! let details = CollectionDetailsExt {
+//!     owner: ALICE,
+//!     owner_deposit: 100,
+//!     items: 2,
+//!     item_metadatas: 0,
+//!     item_configs: 2,
+//!     attributes: 1,
+//! };
+//! assert_eq!(
+//!     GetCollection::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Ok(Some(details))
+//! );
+//!
+//! let v = &vectors::GET_OWNED_ITEMS;
+//! check::<GetOwnedItems>(v, (ALICE, Some(3), None, 2));
+//! let page = Page { items: vec![(3, 42), (3, 43)], next: Some((3, 43)) };
+//! assert_eq!(GetOwnedItems::<DefaultConfig>::decode_output(v.status, &v.output()), Ok(page));
+//!
+//! let v = &vectors::GET_ITEM_OWNER;
+//! check::<GetItemOwner>(v, (3, 42));
+//! assert_eq!(
+//!     GetItemOwner::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Ok(Some(ALICE))
+//! );
+//!
+//! let v = &vectors::GET_BALANCE;
+//! check::<GetBalance>(v, (3, ALICE));
+//! assert_eq!(GetBalance::<DefaultConfig>::decode_output(v.status, &v.output()), Ok(2));
+//!
+//! let v = &vectors::GET_APPROVAL;
+//! check::<GetApproval>(v, (3, 42, BOB));
+//! assert_eq!(
+//!     GetApproval::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Ok(Some(Some(100)))
+//! );
+//!
+//! let v = &vectors::GET_ATTRIBUTE;
+//! let input = (3, Some(42), AttributeNamespaceExt::CollectionOwner, b"name".to_vec());
+//! check::<GetAttribute>(v, input);
+//! assert_eq!(
+//!     GetAttribute::<DefaultConfig>::decode_output(v.status, &v.output()),
+//!     Ok(Some(b"Song".to_vec()))
+//! );
+//!
+//! let v = &vectors::DRY_RUN_TRANSFER;
+//! assert_eq!(Transfer::<DefaultConfig>::dry_run_func_id(), v.func_id);
+//! assert_eq!(Transfer::<DefaultConfig>::encode_input(&(3, 42, BOB)), v.input());
+//! let weight = WeightExt { ref_time: 639_175_461, proof_size: 14_926 };
+//! assert_eq!(
+//!     decode_dry_run(v.status, &v.output()),
+//!     Ok(DryRunResultExt { status: 0, weight, output: vec![] })
+//! );
+//! assert_eq!(
+//!     Transfer::<DefaultConfig>::decode_dry_run(v.status, &v.output()),
+//!     Ok((Ok(()), weight))
+//! );
+//! ```
+
+use crate::AccountId;
+
+mod generated;
+
+pub use generated::*;
+
+/// First sample account.
+pub const ALICE: AccountId = [1; 32];
+/// Second sample account.
+pub const BOB: AccountId = [2; 32];
+
+/// A call of the extension: the function id and the input buffer passed by the contract, and the
+/// status code and the output buffer returned by the runtime. The buffers are hex encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vector {
+    pub func_id: u32,
+    pub input: &'static str,
+    pub status: u32,
+    pub output: &'static str,
+}
+
+impl Vector {
+    /// Bytes of the input buffer.
+    pub fn input(&self) -> Vec<u8> {
+        hex::decode(self.input).expect("vectors are valid hex")
+    }
+
+    /// Bytes of the output buffer.
+    pub fn output(&self) -> Vec<u8> {
+        hex::decode(self.output).expect("vectors are valid hex")
+    }
+}
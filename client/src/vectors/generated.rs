@@ -0,0 +1,118 @@
+//! Generated by the `vectors_match_the_runtime` test of the runtime extension, don't edit.
+//! Regenerate with `UPDATE_VECTORS=1 cargo test -p nfts-extension-impls-runtime vectors`.
+
+use super::Vector;
+
+/// `create` by [`ALICE`](super::ALICE) with the default config, creating the collection `7`.
+pub const CREATE: Vector = Vector {
+    func_id: 0x4e46_0101,
+    input: "0101010101010101010101010101010101010101010101010101010101010101\
+            01010101010000000000010101",
+    status: 0,
+    output: "0007000000",
+};
+
+/// `transfer` of the item `42` to [`BOB`](super::BOB) by a contract that isn't allowed to, with no
+/// output.
+pub const TRANSFER: Vector = Vector {
+    func_id: 0x4e46_0111,
+    input: "030000002a000000020202020202020202020202020202020202020202020202\
+            0202020202020202",
+    status: 2,
+    output: "",
+};
+
+/// `approve_transfer` of the item `42` to [`BOB`](super::BOB) until the block `100`, with no
+/// output.
+pub const APPROVE_TRANSFER: Vector = Vector {
+    func_id: 0x4e46_0112,
+    input: "030000002a000000020202020202020202020202020202020202020202020202\
+            02020202020202020164000000",
+    status: 0,
+    output: "",
+};
+
+/// `batch_transfer` in best effort mode of the items `42` to [`BOB`](super::BOB) and `44` to
+/// [`ALICE`](super::ALICE), the second one being unknown.
+pub const BATCH_TRANSFER: Vector = Vector {
+    func_id: 0x4e46_0110,
+    input: "08030000002a0000000202020202020202020202020202020202020202020202\
+            020202020202020202030000002c000000010101010101010101010101010101\
+            010101010101010101010101010101010101",
+    status: 0,
+    output: "00080000000016000000",
+};
+
+/// `force_set_attribute` of `k` to `v` in the pallet namespace of the collection, by a contract
+/// that isn't trusted.
+pub const FORCE_SET_ATTRIBUTE: Vector = Vector {
+    func_id: 0x4e46_0108,
+    input: "00030000000000046b0476",
+    status: 2,
+    output: "",
+};
+
+/// `get_collection` of a collection owned by [`ALICE`](super::ALICE), with a deposit of `100`, `2`
+/// items, `2` item configs and `1` attribute.
+pub const GET_COLLECTION: Vector = Vector {
+    func_id: 0x4e46_0102,
+    input: "03000000",
+    status: 0,
+    output: "0101010101010101010101010101010101010101010101010101010101010101\
+             0164000000000000000000000000000000020000000000000002000000010000\
+             00",
+};
+
+/// `get_owned_items` of [`ALICE`](super::ALICE) in the collection, by pages of `2`: the items `42`
+/// and `43`, the last one being the cursor of the next page.
+pub const GET_OWNED_ITEMS: Vector = Vector {
+    func_id: 0x4e46_0109,
+    input: "0101010101010101010101010101010101010101010101010101010101010101\
+            01030000000002000000",
+    status: 0,
+    output: "08030000002a000000030000002b00000001030000002b000000",
+};
+
+/// `get_item_owner` of the item `42`, owned by [`ALICE`](super::ALICE).
+pub const GET_ITEM_OWNER: Vector = Vector {
+    func_id: 0x4e46_0114,
+    input: "030000002a000000",
+    status: 0,
+    output: "0101010101010101010101010101010101010101010101010101010101010101\
+             01",
+};
+
+/// `get_balance` of [`ALICE`](super::ALICE) in the collection, `2` items.
+pub const GET_BALANCE: Vector = Vector {
+    func_id: 0x4e46_0115,
+    input: "0300000001010101010101010101010101010101010101010101010101010101\
+            01010101",
+    status: 0,
+    output: "02000000",
+};
+
+/// `get_approval` of [`BOB`](super::BOB) for the item `42`, approved until the block `100`.
+pub const GET_APPROVAL: Vector = Vector {
+    func_id: 0x4e46_0116,
+    input: "030000002a000000020202020202020202020202020202020202020202020202\
+            0202020202020202",
+    status: 0,
+    output: "010164000000",
+};
+
+/// `get_attribute` `name` of the item `42` set by the owner of the collection, `Song`.
+pub const GET_ATTRIBUTE: Vector = Vector {
+    func_id: 0x4e46_0117,
+    input: "03000000012a00000001106e616d65",
+    status: 0,
+    output: "000110536f6e67",
+};
+
+/// Dry run of the [`TRANSFER`] by a contract allowed to, it would succeed.
+pub const DRY_RUN_TRANSFER: Vector = Vector {
+    func_id: 0x4e46_0191,
+    input: "030000002a000000020202020202020202020202020202020202020202020202\
+            0202020202020202",
+    status: 0,
+    output: "00000000250b1926000000004e3a00000000000000",
+};
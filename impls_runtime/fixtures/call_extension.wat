@@ -0,0 +1,48 @@
+;; Calls the chain extension with the function id in the first 4 bytes of its input and the rest of
+;; its input, then returns the status code of the call followed by the output of the extension.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "call_chain_extension"
+		(func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32))
+	)
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) length of the input buffer, 8192
+	(data (i32.const 0) "\00\20")
+
+	;; [4, 8196) input buffer: the function id then the input of the extension
+
+	;; [8196, 8200) length of the output buffer, 8192
+	(data (i32.const 8196) "\00\20")
+
+	;; [8200, 8204) status code of the call
+	;; [8204, 16396) output buffer of the extension
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 4) (i32.const 0))
+
+		(i32.store (i32.const 8200)
+			(call $call_chain_extension
+				(i32.load (i32.const 4))
+				(i32.const 8)
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+				(i32.const 8204)
+				(i32.const 8196)
+			)
+		)
+
+		;; The length of the output buffer is left untouched when the extension writes nothing.
+		(if (i32.eq (i32.load (i32.const 8196)) (i32.const 8192))
+			(then (i32.store (i32.const 8196) (i32.const 0)))
+		)
+
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 8200)
+			(i32.add (i32.load (i32.const 8196)) (i32.const 4))
+		)
+	)
+)
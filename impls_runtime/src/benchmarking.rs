@@ -0,0 +1,456 @@
+//! Benchmarks of the NFTs chain extension's own overhead.
+//!
+//! The chain extension can't be called outside of a contract execution, so each path of
+//! `NftsFunc` is measured without the contract environment: the work done around the buffers
+//! (input decoding, type conversions, call building, pages, output encoding) and, for queries, the
+//! storage reads. Copying the input and output buffers is measured separately by `read_input` and
+//! `write_output`, over their length. The paths taking byte vectors or a batch are measured over
+//! the size of their input.
+//!
+//! To benchmark it, implement `Config` for the runtime and add
+//! `[nfts_extension, NftsExtensionBench::<Runtime>]` to its `define_benchmarks!`, where
+//! `NftsExtensionBench` is this module's `Pallet`.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+use pallet_nfts::{BenchmarkHelper, CollectionSetting, CollectionSettings, ItemSettings};
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Largest buffer benchmarked by `read_input` and `write_output`.
+const MAX_INPUT_LEN: u32 = 16 * 1024;
+
+pub struct Pallet<T: Config>(pallet_nfts::Pallet<T>);
+pub trait Config: pallet_contracts::Config + pallet_nfts::Config {}
+
+fn collection_config<T: Config>() -> CollectionConfigFor<T> {
+    CollectionConfig {
+        settings: CollectionSettings::from_disabled(CollectionSetting::DepositRequired.into()),
+        max_supply: None,
+        mint_settings: MintSettings::default(),
+    }
+}
+
+fn collection_config_ext<T: Config>() -> CollectionConfigExtFor<T> {
+    CollectionConfigExtWrapperFor::<T>::from(collection_config::<T>()).0
+}
+
+fn create_collection<T: Config>(owner: &T::AccountId) -> T::CollectionId {
+    let origin = T::ForceOrigin::try_successful_origin().expect("ForceOrigin has a valid origin");
+    let collection =
+        pallet_nfts::NextCollectionId::<T>::get().unwrap_or(T::CollectionId::initial_value());
+    pallet_nfts::Pallet::<T>::force_create(
+        origin,
+        T::Lookup::unlookup(owner.clone()),
+        collection_config::<T>(),
+    )
+    .expect("collection can be created");
+    collection
+}
+
+fn mint_item<T: Config>(
+    collection: T::CollectionId,
+    index: u16,
+    owner: &T::AccountId,
+) -> T::ItemId {
+    let origin = T::ForceOrigin::try_successful_origin().expect("ForceOrigin has a valid origin");
+    let item = T::Helper::item(index);
+    pallet_nfts::Pallet::<T>::force_mint(
+        origin,
+        collection,
+        item,
+        T::Lookup::unlookup(owner.clone()),
+        ItemConfig {
+            settings: ItemSettings::all_enabled(),
+        },
+    )
+    .expect("item can be minted");
+    item
+}
+
+benchmarks! {
+    read_input {
+        let n in 0 .. MAX_INPUT_LEN;
+        let input = vec![0u8; n as usize].encode();
+    }: {
+        let _: Vec<u8> = Decode::decode(&mut &input[..]).expect("input is valid");
+    }
+
+    write_output {
+        let n in 0 .. MAX_INPUT_LEN;
+        let output = vec![0u8; n as usize];
+    }: {
+        let mut buffer = Vec::with_capacity(output.len());
+        buffer.extend_from_slice(&output);
+    }
+
+    create {
+        let admin: T::AccountId = account("admin", 0, SEED);
+    }: {
+        let config = CollectionConfig {
+            settings: Default::default(),
+            max_supply: None,
+            mint_settings: Default::default(),
+        };
+        let collection = pallet_nfts::NextCollectionId::<T>::get()
+            .unwrap_or(T::CollectionId::initial_value());
+        let call = pallet_nfts::Call::<T>::create {
+            admin: T::Lookup::unlookup(admin),
+            config,
+        };
+        let _ = call.get_dispatch_info();
+        let output: Result<T::CollectionId, NftsError> = Ok(collection);
+        let _ = output.encode();
+    }
+
+    redeposit {
+        let items: BoundedVec<T::ItemId, ConstU32<MAX_REDEPOSIT_ITEMS>> =
+            BoundedVec::truncate_from(
+                (0..MAX_REDEPOSIT_ITEMS as u16).map(T::Helper::item).collect(),
+            );
+    }: {
+        let call = pallet_nfts::Call::<T>::redeposit {
+            collection: T::Helper::collection(0),
+            items: items.into_inner(),
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    batch_mint {
+        let n in 0 .. MAX_BATCH_SIZE;
+        let mint_to: T::AccountId = account("mint_to", 0, SEED);
+        let entries: Vec<BatchMintEntry<T>> = (0..n)
+            .map(|i| (T::Helper::item(i as u16), [0u8; 32], None))
+            .collect();
+        let input = (T::Helper::collection(0), entries, BatchModeExt::Atomic).encode();
+    }: {
+        let (collection, entries, _): BatchMintInput<T, Vec<BatchMintEntry<T>>> =
+            Decode::decode(&mut &input[..]).expect("input is valid");
+        let calls: Vec<pallet_nfts::Call<T>> = entries
+            .into_iter()
+            .map(|(item, _, witness)| pallet_nfts::Call::<T>::mint {
+                collection,
+                item,
+                mint_to: T::Lookup::unlookup(mint_to.clone()),
+                witness_data: witness.map(|witness| MintWitness {
+                    owned_item: witness.owned_item,
+                }),
+            })
+            .collect();
+        for call in &calls {
+            let _ = call.get_dispatch_info();
+        }
+        let output: Result<Vec<u32>, NftsError> = Ok(vec![SUCCESS; n as usize]);
+        let _ = output.encode();
+    }
+
+    batch_transfer {
+        let n in 0 .. MAX_BATCH_SIZE;
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let entries: Vec<BatchTransferEntry<T>> = (0..n)
+            .map(|i| (T::Helper::collection(0), T::Helper::item(i as u16), [0u8; 32]))
+            .collect();
+        let input = (entries, BatchModeExt::Atomic).encode();
+    }: {
+        let (entries, _): BatchTransferInput<Vec<BatchTransferEntry<T>>> =
+            Decode::decode(&mut &input[..]).expect("input is valid");
+        let calls: Vec<pallet_nfts::Call<T>> = entries
+            .into_iter()
+            .map(|(collection, item, _)| pallet_nfts::Call::<T>::transfer {
+                collection,
+                item,
+                dest: T::Lookup::unlookup(dest.clone()),
+            })
+            .collect();
+        for call in &calls {
+            let _ = call.get_dispatch_info();
+        }
+        let output: Result<Vec<u32>, NftsError> = Ok(vec![SUCCESS; n as usize]);
+        let _ = output.encode();
+    }
+
+    transfer {
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: {
+        let call = pallet_nfts::Call::<T>::transfer {
+            collection: T::Helper::collection(0),
+            item: T::Helper::item(0),
+            dest: T::Lookup::unlookup(dest),
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    approve_transfer {
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+    }: {
+        let call = pallet_nfts::Call::<T>::approve_transfer {
+            collection: T::Helper::collection(0),
+            item: T::Helper::item(0),
+            delegate: T::Lookup::unlookup(delegate),
+            maybe_deadline: Some(T::BlockNumber::from(1u32)),
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    cancel_approval {
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+    }: {
+        let call = pallet_nfts::Call::<T>::cancel_approval {
+            collection: T::Helper::collection(0),
+            item: T::Helper::item(0),
+            delegate: T::Lookup::unlookup(delegate),
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    force_create {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let config = collection_config_ext::<T>();
+    }: {
+        let CollectionConfigWrapperFor::<T>(config) = config.into();
+        let collection = pallet_nfts::NextCollectionId::<T>::get()
+            .unwrap_or(T::CollectionId::initial_value());
+        let call = pallet_nfts::Call::<T>::force_create {
+            owner: T::Lookup::unlookup(owner),
+            config,
+        };
+        let _ = call.get_dispatch_info();
+        let output: Result<T::CollectionId, NftsError> = Ok(collection);
+        let _ = output.encode();
+    }
+
+    force_mint {
+        let mint_to: T::AccountId = account("mint_to", 0, SEED);
+        let settings = ItemSettingsExt {
+            transferable: true,
+            unlocked_metadata: true,
+            unlocked_attributes: true,
+        };
+    }: {
+        let ItemSettingsWrapper(settings) = settings.into();
+        let call = pallet_nfts::Call::<T>::force_mint {
+            collection: T::Helper::collection(0),
+            item: T::Helper::item(0),
+            mint_to: T::Lookup::unlookup(mint_to),
+            item_config: ItemConfig { settings },
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    force_collection_owner {
+        let owner: T::AccountId = account("owner", 0, SEED);
+    }: {
+        let call = pallet_nfts::Call::<T>::force_collection_owner {
+            collection: T::Helper::collection(0),
+            owner: T::Lookup::unlookup(owner),
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    force_collection_config {
+        let config = collection_config_ext::<T>();
+    }: {
+        let CollectionConfigWrapperFor::<T>(config) = config.into();
+        let call = pallet_nfts::Call::<T>::force_collection_config {
+            collection: T::Helper::collection(0),
+            config,
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    force_set_attribute {
+        let k in 0 .. T::KeyLimit::get();
+        let v in 0 .. T::ValueLimit::get();
+        let set_as: T::AccountId = account("set_as", 0, SEED);
+        let input = (
+            Some([0u8; 32]),
+            T::Helper::collection(0),
+            Some(T::Helper::item(0)),
+            AttributeNamespaceExt::<[u8; 32]>::CollectionOwner,
+            vec![0u8; k as usize],
+            vec![0u8; v as usize],
+        )
+            .encode();
+    }: {
+        let (_, collection, maybe_item, _, key, value): ForceSetAttributeInput<
+            T,
+            Vec<u8>,
+            Vec<u8>,
+        > = Decode::decode(&mut &input[..]).expect("input is valid");
+        let key: BoundedVec<u8, T::KeyLimit> = bounded(key).expect("key is bounded");
+        let value: BoundedVec<u8, T::ValueLimit> = bounded(value).expect("value is bounded");
+        let call = pallet_nfts::Call::<T>::force_set_attribute {
+            set_as: Some(set_as),
+            collection,
+            maybe_item,
+            namespace: AttributeNamespace::CollectionOwner,
+            key,
+            value,
+        };
+        let _ = call.get_dispatch_info();
+    }
+
+    get_collection {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+    }: {
+        let _ = pallet_nfts::Collection::<T>::get(collection).encode();
+    }
+
+    get_owned_items {
+        let n in 0 .. MAX_PAGE_SIZE;
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+        for i in 0..n {
+            mint_item::<T>(collection, i as u16, &owner);
+        }
+    }: {
+        let items: Vec<(T::CollectionId, T::ItemId)> =
+            pallet_nfts::Account::<T>::iter_key_prefix((&owner,)).take(n as usize).collect();
+        let _ = into_page(items, n).encode();
+    }
+
+    get_collections {
+        let n in 0 .. MAX_PAGE_SIZE;
+        let owner: T::AccountId = account("owner", 0, SEED);
+        for _ in 0..n {
+            create_collection::<T>(&owner);
+        }
+    }: {
+        let collections: Vec<T::CollectionId> =
+            pallet_nfts::Collection::<T>::iter_keys().take(n as usize).collect();
+        let _ = into_page(collections, n).encode();
+    }
+
+    get_owned_collections {
+        let n in 0 .. MAX_PAGE_SIZE;
+        let owner: T::AccountId = account("owner", 0, SEED);
+        for _ in 0..n {
+            create_collection::<T>(&owner);
+        }
+    }: {
+        let collections: Vec<T::CollectionId> =
+            pallet_nfts::CollectionAccount::<T>::iter_key_prefix(&owner)
+                .take(n as usize)
+                .collect();
+        let _ = into_page(collections, n).encode();
+    }
+
+    get_collection_config {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+    }: {
+        let config = pallet_nfts::CollectionConfigOf::<T>::get(collection)
+            .map(|config| CollectionConfigExtWrapperFor::<T>::from(config).0);
+        let _ = config.encode();
+    }
+
+    get_item_config {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+        let item = mint_item::<T>(collection, 0, &owner);
+    }: {
+        let settings = pallet_nfts::ItemConfigOf::<T>::get(collection, item)
+            .map(|config| ItemSettingsExt::from(ItemSettingsFlagsWrapper::from(config.settings).0));
+        let _ = settings.encode();
+    }
+
+    get_item_owner {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+        let item = mint_item::<T>(collection, 0, &owner);
+    }: {
+        let _ = <pallet_nfts::Pallet<T> as Inspect<T::AccountId>>::owner(&collection, &item)
+            .encode();
+    }
+
+    get_balance {
+        let n in 0 .. MAX_PAGE_SIZE;
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+        for i in 0..n {
+            mint_item::<T>(collection, i as u16, &owner);
+        }
+    }: {
+        let balance = pallet_nfts::Account::<T>::iter_key_prefix((&owner, collection))
+            .take(n as usize)
+            .count() as u32;
+        let _ = balance.encode();
+    }
+
+    get_approval {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+        let item = mint_item::<T>(collection, 0, &owner);
+        pallet_nfts::Pallet::<T>::approve_transfer(
+            SystemOrigin::Signed(owner).into(),
+            collection,
+            item,
+            T::Lookup::unlookup(delegate.clone()),
+            None,
+        )
+        .expect("transfer can be approved");
+    }: {
+        let approval = item_details::<T>(collection, item)
+            .and_then(|details| details.approvals.get(&delegate).copied());
+        let _ = approval.encode();
+    }
+
+    get_attribute {
+        let k in 0 .. T::KeyLimit::get();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let collection = create_collection::<T>(&owner);
+        let item = mint_item::<T>(collection, 0, &owner);
+        let key: BoundedVec<u8, T::KeyLimit> = BoundedVec::truncate_from(vec![0u8; k as usize]);
+        let value: BoundedVec<u8, T::ValueLimit> =
+            BoundedVec::truncate_from(vec![0u8; T::ValueLimit::get() as usize]);
+        let origin =
+            T::ForceOrigin::try_successful_origin().expect("ForceOrigin has a valid origin");
+        pallet_nfts::Pallet::<T>::force_set_attribute(
+            origin,
+            None,
+            collection,
+            Some(item),
+            AttributeNamespace::CollectionOwner,
+            key.clone(),
+            value,
+        )
+        .expect("attribute can be set");
+        let input = (
+            collection,
+            Some(item),
+            AttributeNamespaceExt::<[u8; 32]>::CollectionOwner,
+            key.into_inner(),
+        )
+            .encode();
+    }: {
+        let (collection, maybe_item, _, key): GetAttributeInput<T, Vec<u8>> =
+            Decode::decode(&mut &input[..]).expect("input is valid");
+        let key: BoundedVec<u8, T::KeyLimit> = bounded(key).expect("key is bounded");
+        let namespace = AttributeNamespace::<T::AccountId>::CollectionOwner;
+        let value = pallet_nfts::Attribute::<T>::get((collection, maybe_item, namespace, key))
+            .map(|(value, _)| value.into_inner());
+        let output: Result<Option<Vec<u8>>, NftsError> = Ok(value);
+        let _ = output.encode();
+    }
+
+    get_capabilities {
+    }: {
+        let _ = capabilities::<T>().encode();
+    }
+
+    get_constant {
+    }: {
+        let _ = T::CollectionDeposit::get().encode();
+    }
+
+    get_nfts_constants {
+    }: {
+        let _ = nfts_constants::<T>().encode();
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(&[]), crate::mock::Test);
+}
@@ -0,0 +1,162 @@
+//! Conversions between the bit flags of the extension types and the ones of the nfts pallet.
+//!
+//! Both sides share the same bits, so the conversions are lossless and the SCALE encodings are
+//! identical:
+//!
+//! ```
+//! use codec::Encode;
+//! use nfts_extension_impls_runtime::flags::{
+//!     CollectionRolesFlagsWrapper, CollectionRolesWrapper, CollectionSettingsFlagsWrapper,
+//!     CollectionSettingsWrapper, ItemSettingsFlagsWrapper, ItemSettingsWrapper,
+//!     PalletFeaturesFlagsWrapper, PalletFeaturesWrapper,
+//! };
+//! use nfts_extension_types::flags::{
+//!     CollectionRolesFlags, CollectionSettingsFlags, ItemSettingsFlags, PalletFeaturesFlags,
+//! };
+//! use nfts_extension_types::{CollectionSettingsExt, ItemSettingsExt};
+//! use pallet_nfts::{
+//!     CollectionRole, CollectionRoles, CollectionSetting, CollectionSettings, ItemSetting,
+//!     ItemSettings, PalletFeature, PalletFeatures,
+//! };
+//!
+//! // Collection settings
+//! let settings = CollectionSettings::from_disabled(
+//!     CollectionSetting::TransferableItems | CollectionSetting::DepositRequired,
+//! );
+//! let CollectionSettingsFlagsWrapper(flags) = settings.into();
+//! assert_eq!(flags.encode(), settings.encode());
+//! assert_eq!(CollectionSettingsWrapper::from(flags).0, settings);
+//! let ext = CollectionSettingsExt::from(flags);
+//! assert!(!ext.transferable_items && !ext.deposit_required && ext.unlocked_metadata);
+//! assert_eq!(CollectionSettingsWrapper::from(ext).0, settings);
+//!
+//! // Item settings
+//! let settings = ItemSettings::from_disabled(ItemSetting::UnlockedMetadata.into());
+//! let ItemSettingsFlagsWrapper(flags) = settings.into();
+//! assert_eq!(flags.encode(), settings.encode());
+//! assert_eq!(ItemSettingsWrapper::from(flags).0, settings);
+//! assert_eq!(ItemSettingsWrapper::from(ItemSettingsExt::from(flags)).0, settings);
+//!
+//! // Pallet features
+//! let features = PalletFeatures::from_disabled(PalletFeature::Swaps | PalletFeature::Trading);
+//! let encoded = features.encode();
+//! let PalletFeaturesFlagsWrapper(flags) = features.into();
+//! assert_eq!(flags.encode(), encoded);
+//! assert_eq!(PalletFeaturesWrapper::from(flags).0.encode(), encoded);
+//!
+//! // Collection roles
+//! let roles = CollectionRoles(CollectionRole::Issuer | CollectionRole::Admin);
+//! let CollectionRolesFlagsWrapper(flags) = roles.into();
+//! assert_eq!(flags.bits(), CollectionRolesFlags::ISSUER | CollectionRolesFlags::ADMIN);
+//! assert_eq!(flags.encode(), roles.encode());
+//! assert_eq!(CollectionRolesWrapper::from(flags).0, roles);
+//!
+//! // Every combination of bits round-trips.
+//! for bits in 0..=CollectionSettingsFlags::ALL {
+//!     let flags = CollectionSettingsFlags::from_bits_truncate(bits);
+//!     let CollectionSettingsWrapper(settings) = flags.into();
+//!     assert_eq!(settings.encode(), flags.encode());
+//!     assert_eq!(CollectionSettingsFlagsWrapper::from(settings).0, flags);
+//! }
+//! for bits in 0..=ItemSettingsFlags::ALL {
+//!     let flags = ItemSettingsFlags::from_bits_truncate(bits);
+//!     assert_eq!(ItemSettingsFlagsWrapper::from(ItemSettingsWrapper::from(flags).0).0, flags);
+//! }
+//! for bits in 0..=PalletFeaturesFlags::ALL {
+//!     let flags = PalletFeaturesFlags::from_bits_truncate(bits);
+//!     assert_eq!(PalletFeaturesFlagsWrapper::from(PalletFeaturesWrapper::from(flags).0).0, flags);
+//! }
+//! for bits in 0..=CollectionRolesFlags::ALL {
+//!     let flags = CollectionRolesFlags::from_bits_truncate(bits);
+//!     let CollectionRolesWrapper(roles) = flags.into();
+//!     assert_eq!(CollectionRolesFlagsWrapper::from(roles).0, flags);
+//! }
+//! ```
+
+use enumflags2::BitFlags;
+use nfts_extension_types::flags::{
+    CollectionRolesFlags, CollectionSettingsFlags, ItemSettingsFlags, PalletFeaturesFlags,
+};
+use nfts_extension_types::{CollectionSettingsExt, ItemSettingsExt, PalletFeaturesExt};
+use pallet_nfts::{CollectionRoles, CollectionSettings, ItemSettings, PalletFeatures};
+
+/// Wrapper to convert the extension collection settings to the ones of the nfts pallet
+pub struct CollectionSettingsWrapper(pub CollectionSettings);
+impl From<CollectionSettingsFlags> for CollectionSettingsWrapper {
+    fn from(value: CollectionSettingsFlags) -> Self {
+        CollectionSettingsWrapper(CollectionSettings(BitFlags::from_bits_truncate(
+            value.bits(),
+        )))
+    }
+}
+impl From<CollectionSettingsExt> for CollectionSettingsWrapper {
+    fn from(value: CollectionSettingsExt) -> Self {
+        CollectionSettingsFlags::from(value).into()
+    }
+}
+
+/// Wrapper to convert the collection settings of the nfts pallet to the extension flags
+pub struct CollectionSettingsFlagsWrapper(pub CollectionSettingsFlags);
+impl From<CollectionSettings> for CollectionSettingsFlagsWrapper {
+    fn from(value: CollectionSettings) -> Self {
+        CollectionSettingsFlagsWrapper(CollectionSettingsFlags::from_bits_truncate(value.0.bits()))
+    }
+}
+
+/// Wrapper to convert the extension item settings to the ones of the nfts pallet
+pub struct ItemSettingsWrapper(pub ItemSettings);
+impl From<ItemSettingsFlags> for ItemSettingsWrapper {
+    fn from(value: ItemSettingsFlags) -> Self {
+        ItemSettingsWrapper(ItemSettings(BitFlags::from_bits_truncate(value.bits())))
+    }
+}
+impl From<ItemSettingsExt> for ItemSettingsWrapper {
+    fn from(value: ItemSettingsExt) -> Self {
+        ItemSettingsFlags::from(value).into()
+    }
+}
+
+/// Wrapper to convert the item settings of the nfts pallet to the extension flags
+pub struct ItemSettingsFlagsWrapper(pub ItemSettingsFlags);
+impl From<ItemSettings> for ItemSettingsFlagsWrapper {
+    fn from(value: ItemSettings) -> Self {
+        ItemSettingsFlagsWrapper(ItemSettingsFlags::from_bits_truncate(value.0.bits()))
+    }
+}
+
+/// Wrapper to convert the extension features to the ones of the nfts pallet
+pub struct PalletFeaturesWrapper(pub PalletFeatures);
+impl From<PalletFeaturesFlags> for PalletFeaturesWrapper {
+    fn from(value: PalletFeaturesFlags) -> Self {
+        PalletFeaturesWrapper(PalletFeatures(BitFlags::from_bits_truncate(value.bits())))
+    }
+}
+impl From<PalletFeaturesExt> for PalletFeaturesWrapper {
+    fn from(value: PalletFeaturesExt) -> Self {
+        PalletFeaturesFlags::from(value).into()
+    }
+}
+
+/// Wrapper to convert the features of the nfts pallet to the extension flags
+pub struct PalletFeaturesFlagsWrapper(pub PalletFeaturesFlags);
+impl From<PalletFeatures> for PalletFeaturesFlagsWrapper {
+    fn from(value: PalletFeatures) -> Self {
+        PalletFeaturesFlagsWrapper(PalletFeaturesFlags::from_bits_truncate(value.0.bits()))
+    }
+}
+
+/// Wrapper to convert the extension roles to the ones of the nfts pallet
+pub struct CollectionRolesWrapper(pub CollectionRoles);
+impl From<CollectionRolesFlags> for CollectionRolesWrapper {
+    fn from(value: CollectionRolesFlags) -> Self {
+        CollectionRolesWrapper(CollectionRoles(BitFlags::from_bits_truncate(value.bits())))
+    }
+}
+
+/// Wrapper to convert the roles of the nfts pallet to the extension flags
+pub struct CollectionRolesFlagsWrapper(pub CollectionRolesFlags);
+impl From<CollectionRoles> for CollectionRolesFlagsWrapper {
+    fn from(value: CollectionRoles) -> Self {
+        CollectionRolesFlagsWrapper(CollectionRolesFlags::from_bits_truncate(value.0.bits()))
+    }
+}
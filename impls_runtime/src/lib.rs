@@ -1,151 +1,1051 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 mod errors;
+pub mod flags;
+#[cfg(test)]
+mod mock;
+pub mod permissions;
+#[cfg(test)]
+mod tests;
+pub mod weights;
 
-use frame_support::traits::Currency;
+use frame_support::dispatch::{extract_actual_weight, GetDispatchInfo};
+use frame_support::storage::with_transaction;
+use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+use frame_support::traits::{
+    ConstU32, Contains, Currency, GetCallName, Nothing, UnfilteredDispatchable,
+};
+use frame_support::{BoundedBTreeMap, BoundedVec};
 use pallet_contracts::chain_extension::{
-    ChainExtension, Environment, Ext, InitState, RetVal, SysConfig,
+    BufInBufOutState, ChainExtension, Environment, Ext, InitState, RegisteredChainExtension,
+    RetVal, SysConfig,
 };
 use pallet_nfts::{
-    weights::WeightInfo, CollectionConfig, CollectionSetting, CollectionSettings, ItemSetting,
-    MintSettings,
+    AttributeNamespace, CollectionConfig, Incrementable, ItemConfig, MintSettings, MintType,
+    MintWitness,
 };
 use sp_runtime::traits::StaticLookup;
-use sp_runtime::{DispatchError, SaturatedConversion};
+use sp_runtime::{DispatchError, DispatchResult, SaturatedConversion, TransactionOutcome};
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::Get;
 
-use crate::errors::NftsError;
-use nfts_extension_types::CollectionConfigExt;
+use crate::errors::{nfts_error, NftsError, SUCCESS};
+use crate::flags::{
+    CollectionSettingsFlagsWrapper, CollectionSettingsWrapper, ItemSettingsFlagsWrapper,
+    ItemSettingsWrapper, PalletFeaturesFlagsWrapper,
+};
+use crate::permissions::CallFilter;
+use crate::weights::WeightInfo;
+use alloc::format;
+use nfts_extension_types::ids::{
+    func_id, function, split_func_id, strip_dry_run, EXTENSION_ID, FUNCTIONS, LEGACY_FUNCTIONS,
+    LEGACY_VERSION, VERSION,
+};
+use nfts_extension_types::{
+    AttributeNamespaceExt, BatchModeExt, CapabilitiesExt, CollectionConfigExt, DryRunResultExt,
+    ItemSettingsExt, MintSettingsExt, MintTypeExt, MintWitnessExt, NftsConstantsExt, Page,
+    WeightExt, MAX_BATCH_SIZE, MAX_PAGE_SIZE, MAX_REDEPOSIT_ITEMS,
+};
+use sp_std::fmt::Debug;
 use sp_std::marker::PhantomData;
+use sp_std::vec;
+use sp_std::vec::Vec;
 
+use frame_system::RawOrigin as SystemOrigin;
 use pallet_contracts::RawOrigin;
 
-enum NftsFunc {
+/// Functions of the extension.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NftsFunc {
     // Extrinsics
     Create,
+    Redeposit,
+    BatchMint,
+    BatchTransfer,
+    Transfer,
+    ApproveTransfer,
+    CancelApproval,
+    // Privileged extrinsics
+    ForceCreate,
+    ForceMint,
+    ForceCollectionOwner,
+    ForceCollectionConfig,
+    ForceSetAttribute,
     // Chain state
     GetCollection,
+    GetOwnedItems,
+    GetCollections,
+    GetOwnedCollections,
+    GetCollectionConfig,
+    GetItemConfig,
+    GetCapabilities,
+    GetItemOwner,
+    GetBalance,
+    GetApproval,
+    GetAttribute,
     // Constants
     GetApprovalsLimit,
     GetAttributeDepositBase,
     GetCollectionDeposit,
     GetDepositPerByte,
+    GetNftsConstants,
+}
+
+impl NftsFunc {
+    /// Whether the function dispatches a call of the nfts pallet, as opposed to a query.
+    pub fn is_extrinsic(&self) -> bool {
+        matches!(
+            self,
+            NftsFunc::Create
+                | NftsFunc::Redeposit
+                | NftsFunc::BatchMint
+                | NftsFunc::BatchTransfer
+                | NftsFunc::Transfer
+                | NftsFunc::ApproveTransfer
+                | NftsFunc::CancelApproval
+                | NftsFunc::ForceCreate
+                | NftsFunc::ForceMint
+                | NftsFunc::ForceCollectionOwner
+                | NftsFunc::ForceCollectionConfig
+                | NftsFunc::ForceSetAttribute
+        )
+    }
 }
 
 impl TryFrom<u16> for NftsFunc {
-    type Error = DispatchError;
+    type Error = NftsError;
 
+    /// Decode the low 16 bits of a function id, see `nfts_extension_types::ids` for the layout.
+    /// The current version is served along the functions of the legacy version.
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(NftsFunc::Create),
-            2 => Ok(NftsFunc::GetCollection),
-            100 => Ok(NftsFunc::GetApprovalsLimit),
-            101 => Ok(NftsFunc::GetAttributeDepositBase),
-            102 => Ok(NftsFunc::GetCollectionDeposit),
-            103 => Ok(NftsFunc::GetDepositPerByte),
-            _ => Err(DispatchError::Other(
-                "PalletNftsExtension: Unimplemented func_id",
-            )),
+        let (version, func) = split_func_id(value);
+        let supported = match version {
+            VERSION => true,
+            LEGACY_VERSION => LEGACY_FUNCTIONS.contains(&func),
+            _ => false,
+        };
+        if !supported {
+            return Err(NftsError::UnsupportedFunction);
+        }
+        match func {
+            function::CREATE => Ok(NftsFunc::Create),
+            function::GET_COLLECTION => Ok(NftsFunc::GetCollection),
+            function::REDEPOSIT => Ok(NftsFunc::Redeposit),
+            function::FORCE_CREATE => Ok(NftsFunc::ForceCreate),
+            function::FORCE_MINT => Ok(NftsFunc::ForceMint),
+            function::FORCE_COLLECTION_OWNER => Ok(NftsFunc::ForceCollectionOwner),
+            function::FORCE_COLLECTION_CONFIG => Ok(NftsFunc::ForceCollectionConfig),
+            function::FORCE_SET_ATTRIBUTE => Ok(NftsFunc::ForceSetAttribute),
+            function::GET_OWNED_ITEMS => Ok(NftsFunc::GetOwnedItems),
+            function::GET_COLLECTIONS => Ok(NftsFunc::GetCollections),
+            function::GET_OWNED_COLLECTIONS => Ok(NftsFunc::GetOwnedCollections),
+            function::GET_COLLECTION_CONFIG => Ok(NftsFunc::GetCollectionConfig),
+            function::GET_ITEM_CONFIG => Ok(NftsFunc::GetItemConfig),
+            function::GET_CAPABILITIES => Ok(NftsFunc::GetCapabilities),
+            function::BATCH_MINT => Ok(NftsFunc::BatchMint),
+            function::BATCH_TRANSFER => Ok(NftsFunc::BatchTransfer),
+            function::TRANSFER => Ok(NftsFunc::Transfer),
+            function::APPROVE_TRANSFER => Ok(NftsFunc::ApproveTransfer),
+            function::CANCEL_APPROVAL => Ok(NftsFunc::CancelApproval),
+            function::GET_ITEM_OWNER => Ok(NftsFunc::GetItemOwner),
+            function::GET_BALANCE => Ok(NftsFunc::GetBalance),
+            function::GET_APPROVAL => Ok(NftsFunc::GetApproval),
+            function::GET_ATTRIBUTE => Ok(NftsFunc::GetAttribute),
+            function::GET_APPROVALS_LIMIT => Ok(NftsFunc::GetApprovalsLimit),
+            function::GET_ATTRIBUTE_DEPOSIT_BASE => Ok(NftsFunc::GetAttributeDepositBase),
+            function::GET_COLLECTION_DEPOSIT => Ok(NftsFunc::GetCollectionDeposit),
+            function::GET_DEPOSIT_PER_BYTE => Ok(NftsFunc::GetDepositPerByte),
+            function::GET_NFTS_CONSTANTS => Ok(NftsFunc::GetNftsConstants),
+            _ => Err(NftsError::UnsupportedFunction),
         }
     }
 }
 
+/// Target of the extension logs.
+pub const LOG_TARGET: &str = "runtime::nfts-extension";
+
+/// Runtime configuration of the NFTs chain extension.
+pub trait NftsExtensionConfig<T: SysConfig> {
+    /// Contracts allowed to call the privileged `force_*` functions, which are dispatched with a
+    /// root origin. Any other contract gets `NoPermission`.
+    type TrustedContracts: Contains<T::AccountId>;
+    /// Which contracts may call which functions, see the `permissions` module. A denied call
+    /// returns `NoPermission`. The force functions also require the contract to be trusted.
+    type CallFilter: CallFilter<T::AccountId>;
+    /// Weights of the extension's own overhead, on top of the weights of the pallet calls.
+    type WeightInfo: WeightInfo;
+}
+
+/// Default configuration: no contract is trusted with the privileged functions, every other
+/// function is open. The weights price the storage reads with the runtime's `DbWeight`.
+impl<T: SysConfig> NftsExtensionConfig<T> for () {
+    type TrustedContracts = Nothing;
+    type CallFilter = ();
+    type WeightInfo = weights::SubstrateWeight<T>;
+}
+
 /// Pallet Assets chain extension.
-pub struct NftsExtension<T>(PhantomData<T>);
+pub struct NftsExtension<T, C = ()>(PhantomData<(T, C)>);
 
-impl<T> Default for NftsExtension<T> {
+impl<T, C> Default for NftsExtension<T, C> {
     fn default() -> Self {
         NftsExtension(PhantomData)
     }
 }
 
-impl<T> ChainExtension<T> for NftsExtension<T>
+/// Lets the extension be part of a tuple of chain extensions, the contracts select it with the
+/// high 16 bits of the function id.
+impl<T, C> RegisteredChainExtension<T> for NftsExtension<T, C>
+where
+    T: pallet_contracts::Config + pallet_nfts::Config,
+    C: NftsExtensionConfig<T>,
+    <<T as SysConfig>::Lookup as StaticLookup>::Source: From<<T as SysConfig>::AccountId>,
+    <T as SysConfig>::AccountId: From<[u8; 32]>,
+    <T as SysConfig>::RuntimeOrigin: From<RawOrigin<<T as SysConfig>::AccountId>>,
+{
+    const ID: u16 = EXTENSION_ID;
+}
+
+impl<T, C> ChainExtension<T> for NftsExtension<T, C>
 where
     T: pallet_contracts::Config + pallet_nfts::Config,
+    C: NftsExtensionConfig<T>,
     <<T as SysConfig>::Lookup as StaticLookup>::Source: From<<T as SysConfig>::AccountId>,
     <T as SysConfig>::AccountId: From<[u8; 32]>,
     <T as SysConfig>::RuntimeOrigin: From<RawOrigin<<T as SysConfig>::AccountId>>,
 {
     fn call<E: Ext<T = T>>(
         &mut self,
-        env: Environment<E, InitState>,
+        mut env: Environment<E, InitState>,
     ) -> Result<RetVal, DispatchError> {
-        let func_id: NftsFunc = env.func_id().try_into()?;
+        let (id, dry_run) = strip_dry_run(env.func_id());
+        let func_id = NftsFunc::try_from(id).and_then(|func_id| {
+            let (version, _) = split_func_id(id);
+            if dry_run && !(func_id.is_extrinsic() && version == VERSION) {
+                return Err(NftsError::UnsupportedFunction);
+            }
+            Ok(func_id)
+        });
+        let func_id = match func_id {
+            Ok(func_id) => func_id,
+            Err(e) => {
+                log::debug!(
+                    target: LOG_TARGET,
+                    "unsupported function id {:#06x}",
+                    env.func_id(),
+                );
+                return Ok(RetVal::Converging(e as u32));
+            }
+        };
+
+        let caller = env.ext().address().clone();
+        env.charge_weight(C::CallFilter::weight())?;
+        if !C::CallFilter::allows(&caller, func_id) {
+            log::debug!(
+                target: LOG_TARGET,
+                "{:?} denied to contract {:?}",
+                func_id,
+                caller,
+            );
+            return Ok(RetVal::Converging(NftsError::NoPermission as u32));
+        }
         let mut env = env.buf_in_buf_out();
 
+        let gas_left = env.ext().gas_meter().gas_left();
+        let result = if dry_run {
+            Self::dry_run(func_id, &mut env)
+        } else {
+            Self::call_func(func_id, &mut env, &mut None)
+        };
+        let charged = gas_left.saturating_sub(env.ext().gas_meter().gas_left());
+        match &result {
+            Ok(RetVal::Converging(status)) => log::debug!(
+                target: LOG_TARGET,
+                "{:?} called by {:?}: status {}, weight charged {:?}",
+                func_id,
+                caller,
+                status,
+                charged,
+            ),
+            Ok(_) => (),
+            Err(e) => log::debug!(
+                target: LOG_TARGET,
+                "{:?} called by {:?}: trapped with {:?}, weight charged {:?}",
+                func_id,
+                caller,
+                e,
+                charged,
+            ),
+        }
+        result
+    }
+}
+
+impl<T, C> NftsExtension<T, C>
+where
+    T: pallet_contracts::Config + pallet_nfts::Config,
+    C: NftsExtensionConfig<T>,
+    <<T as SysConfig>::Lookup as StaticLookup>::Source: From<<T as SysConfig>::AccountId>,
+    <T as SysConfig>::AccountId: From<[u8; 32]>,
+    <T as SysConfig>::RuntimeOrigin: From<RawOrigin<<T as SysConfig>::AccountId>>,
+{
+    /// Run a function in a storage transaction that is always rolled back, then write its status,
+    /// the weight it consumed and the output it wrote. That weight is still charged.
+    fn dry_run<E: Ext<T = T>>(
+        func_id: NftsFunc,
+        env: &mut Environment<E, BufInBufOutState>,
+    ) -> Result<RetVal, DispatchError> {
+        let gas_left = env.ext().gas_meter().gas_left();
+        let mut function_output = Some(Vec::new());
+        let result = with_transaction(|| {
+            TransactionOutcome::Rollback(Self::call_func(func_id, env, &mut function_output))
+        })?;
+        let weight = gas_left.saturating_sub(env.ext().gas_meter().gas_left());
+
+        let status = match result {
+            RetVal::Converging(status) => status,
+            RetVal::Diverging { .. } => NftsError::UnknownError as u32,
+        };
+        let output = DryRunResultExt {
+            status,
+            weight: WeightExt {
+                ref_time: weight.ref_time(),
+                proof_size: weight.proof_size(),
+            },
+            output: function_output.unwrap_or_default(),
+        };
+        Self::write_output(env, &mut None, &output)?;
+        Ok(RetVal::Converging(SUCCESS))
+    }
+
+    /// Run a function of the extension once its caller has been allowed. In a dry run,
+    /// `dry_run_output` is `Some` and keeps the output instead of the contract, see `write_output`.
+    fn call_func<E: Ext<T = T>>(
+        func_id: NftsFunc,
+        env: &mut Environment<E, BufInBufOutState>,
+        dry_run_output: &mut Option<Vec<u8>>,
+    ) -> Result<RetVal, DispatchError> {
         match func_id {
             NftsFunc::Create => {
                 let args: CreateInput<NftsBalanceOf<T>, T::BlockNumber, T::CollectionId> =
-                    env.read_as()?;
+                    Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::create())?;
                 let admin: T::AccountId = args.admin.into();
-                //let CollectionConfigWrapperFor::<T>(config) = args.config.into();
 
-                let base_weight = <T as pallet_nfts::Config>::WeightInfo::create();
-                env.charge_weight(base_weight)?;
+                // Same id the pallet picks, the read is already part of the create weight.
+                let collection = pallet_nfts::NextCollectionId::<T>::get()
+                    .unwrap_or(T::CollectionId::initial_value());
 
                 let caller = env.ext().address().clone();
-                let call_result = pallet_nfts::Pallet::<T>::create(
-                    RawOrigin::Contract(caller).into(),
-                    admin.into(),
-                    CollectionConfig {
+                // The config of the input isn't applied, the collections are created with the
+                // default config of the pallet. `force_create` applies it.
+                let call = pallet_nfts::Call::<T>::create {
+                    admin: admin.into(),
+                    config: CollectionConfig {
                         settings: Default::default(),
                         max_supply: None,
                         mint_settings: Default::default(),
                     },
+                };
+                let call_result =
+                    Self::dispatch_call(env, RawOrigin::Contract(caller).into(), call)?;
+                if call_result.is_ok() {
+                    let output: Result<T::CollectionId, NftsError> = Ok(collection);
+                    Self::write_output(env, dry_run_output, &output)?;
+                }
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::Redeposit => {
+                let input = Self::read_unbounded_input::<
+                    _,
+                    (T::CollectionId, Vec<T::ItemId>),
+                    (
+                        T::CollectionId,
+                        BoundedVec<T::ItemId, ConstU32<MAX_REDEPOSIT_ITEMS>>,
+                    ),
+                >(env)?;
+                let (collection, items) = match input {
+                    Ok(input) => input,
+                    Err(e) => return Ok(RetVal::Converging(e as u32)),
+                };
+                if items.len() as u32 > MAX_REDEPOSIT_ITEMS {
+                    return Ok(RetVal::Converging(NftsError::InputTooLarge as u32));
+                }
+                env.charge_weight(C::WeightInfo::redeposit())?;
+
+                // The declared weight of the call is `WeightInfo::redeposit(items.len())`.
+                let caller = env.ext().address().clone();
+                let call = pallet_nfts::Call::<T>::redeposit { collection, items };
+                let call_result =
+                    Self::dispatch_call(env, RawOrigin::Contract(caller).into(), call)?;
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::BatchMint => {
+                let input = Self::read_unbounded_input::<
+                    _,
+                    BatchMintInput<T, Vec<BatchMintEntry<T>>>,
+                    BatchMintInput<T, BoundedVec<BatchMintEntry<T>, ConstU32<MAX_BATCH_SIZE>>>,
+                >(env)?;
+                let (collection, entries, mode) = match input {
+                    Ok(input) => input,
+                    Err(e) => return Ok(RetVal::Converging(e as u32)),
+                };
+                if entries.len() as u32 > MAX_BATCH_SIZE {
+                    return Ok(RetVal::Converging(NftsError::InputTooLarge as u32));
+                }
+                env.charge_weight(C::WeightInfo::batch_mint(entries.len() as u32))?;
+
+                let calls = entries
+                    .into_iter()
+                    .map(|(item, mint_to, witness)| {
+                        let mint_to: T::AccountId = mint_to.into();
+                        pallet_nfts::Call::<T>::mint {
+                            collection,
+                            item,
+                            mint_to: mint_to.into(),
+                            witness_data: witness.map(|witness| MintWitness {
+                                owned_item: witness.owned_item,
+                            }),
+                        }
+                    })
+                    .collect();
+                let caller = env.ext().address().clone();
+                return Self::dispatch_batch(
+                    env,
+                    dry_run_output,
+                    RawOrigin::Contract(caller).into(),
+                    calls,
+                    mode,
                 );
-                return match call_result {
-                    Err(e) => {
-                        let mapped_error = NftsError::try_from(e)?;
-                        Ok(RetVal::Converging(mapped_error as u32))
-                    }
-                    Ok(_) => Ok(RetVal::Converging(NftsError::Success as u32)),
+            }
+            NftsFunc::BatchTransfer => {
+                let input = Self::read_unbounded_input::<
+                    _,
+                    BatchTransferInput<Vec<BatchTransferEntry<T>>>,
+                    BatchTransferInput<BoundedVec<BatchTransferEntry<T>, ConstU32<MAX_BATCH_SIZE>>>,
+                >(env)?;
+                let (entries, mode) = match input {
+                    Ok(input) => input,
+                    Err(e) => return Ok(RetVal::Converging(e as u32)),
+                };
+                if entries.len() as u32 > MAX_BATCH_SIZE {
+                    return Ok(RetVal::Converging(NftsError::InputTooLarge as u32));
+                }
+                env.charge_weight(C::WeightInfo::batch_transfer(entries.len() as u32))?;
+
+                let calls = entries
+                    .into_iter()
+                    .map(|(collection, item, dest)| {
+                        let dest: T::AccountId = dest.into();
+                        pallet_nfts::Call::<T>::transfer {
+                            collection,
+                            item,
+                            dest: dest.into(),
+                        }
+                    })
+                    .collect();
+                let caller = env.ext().address().clone();
+                return Self::dispatch_batch(
+                    env,
+                    dry_run_output,
+                    RawOrigin::Contract(caller).into(),
+                    calls,
+                    mode,
+                );
+            }
+            NftsFunc::Transfer => {
+                let (collection, item, dest): (T::CollectionId, T::ItemId, [u8; 32]) =
+                    Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::transfer())?;
+                let dest: T::AccountId = dest.into();
+
+                let caller = env.ext().address().clone();
+                let call = pallet_nfts::Call::<T>::transfer {
+                    collection,
+                    item,
+                    dest: dest.into(),
                 };
+                let call_result =
+                    Self::dispatch_call(env, RawOrigin::Contract(caller).into(), call)?;
+                return map_dispatch_result(call_result);
             }
+            NftsFunc::ApproveTransfer => {
+                let (collection, item, delegate, maybe_deadline): (
+                    T::CollectionId,
+                    T::ItemId,
+                    [u8; 32],
+                    Option<T::BlockNumber>,
+                ) = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::approve_transfer())?;
+                let delegate: T::AccountId = delegate.into();
 
-            NftsFunc::GetCollection => {
-                let id: T::CollectionId = env.read_as()?;
+                let caller = env.ext().address().clone();
+                let call = pallet_nfts::Call::<T>::approve_transfer {
+                    collection,
+                    item,
+                    delegate: delegate.into(),
+                    maybe_deadline,
+                };
+                let call_result =
+                    Self::dispatch_call(env, RawOrigin::Contract(caller).into(), call)?;
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::CancelApproval => {
+                let (collection, item, delegate): (T::CollectionId, T::ItemId, [u8; 32]) =
+                    Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::cancel_approval())?;
+                let delegate: T::AccountId = delegate.into();
+
+                let caller = env.ext().address().clone();
+                let call = pallet_nfts::Call::<T>::cancel_approval {
+                    collection,
+                    item,
+                    delegate: delegate.into(),
+                };
+                let call_result =
+                    Self::dispatch_call(env, RawOrigin::Contract(caller).into(), call)?;
+                return map_dispatch_result(call_result);
+            }
 
-                let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
-                env.charge_weight(base_weight)?;
+            // Privileged extrinsics
+            NftsFunc::ForceCreate => {
+                // Checked first, the untrusted contracts pay for nothing else.
+                if !C::TrustedContracts::contains(env.ext().address()) {
+                    return Ok(RetVal::Converging(NftsError::NoPermission as u32));
+                }
+                let (owner, config): ([u8; 32], CollectionConfigExtFor<T>) = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::force_create())?;
+                let owner: T::AccountId = owner.into();
+                let CollectionConfigWrapperFor::<T>(config) = config.into();
+
+                // Same id the pallet picks, the read is already part of the force_create weight.
+                let collection = pallet_nfts::NextCollectionId::<T>::get()
+                    .unwrap_or(T::CollectionId::initial_value());
+
+                let call = pallet_nfts::Call::<T>::force_create {
+                    owner: owner.into(),
+                    config,
+                };
+                let call_result = Self::dispatch_call(env, SystemOrigin::Root.into(), call)?;
+                if call_result.is_ok() {
+                    let output: Result<T::CollectionId, NftsError> = Ok(collection);
+                    Self::write_output(env, dry_run_output, &output)?;
+                }
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::ForceMint => {
+                if !C::TrustedContracts::contains(env.ext().address()) {
+                    return Ok(RetVal::Converging(NftsError::NoPermission as u32));
+                }
+                let (collection, item, mint_to, item_settings): (
+                    T::CollectionId,
+                    T::ItemId,
+                    [u8; 32],
+                    ItemSettingsExt,
+                ) = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::force_mint())?;
+                let mint_to: T::AccountId = mint_to.into();
+                let ItemSettingsWrapper(settings) = item_settings.into();
+
+                let call = pallet_nfts::Call::<T>::force_mint {
+                    collection,
+                    item,
+                    mint_to: mint_to.into(),
+                    item_config: ItemConfig { settings },
+                };
+                let call_result = Self::dispatch_call(env, SystemOrigin::Root.into(), call)?;
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::ForceCollectionOwner => {
+                if !C::TrustedContracts::contains(env.ext().address()) {
+                    return Ok(RetVal::Converging(NftsError::NoPermission as u32));
+                }
+                let (collection, owner): (T::CollectionId, [u8; 32]) = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::force_collection_owner())?;
+                let owner: T::AccountId = owner.into();
+
+                let call = pallet_nfts::Call::<T>::force_collection_owner {
+                    collection,
+                    owner: owner.into(),
+                };
+                let call_result = Self::dispatch_call(env, SystemOrigin::Root.into(), call)?;
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::ForceCollectionConfig => {
+                if !C::TrustedContracts::contains(env.ext().address()) {
+                    return Ok(RetVal::Converging(NftsError::NoPermission as u32));
+                }
+                let (collection, config): (T::CollectionId, CollectionConfigExtFor<T>) =
+                    Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::force_collection_config())?;
+                let CollectionConfigWrapperFor::<T>(config) = config.into();
+
+                let call = pallet_nfts::Call::<T>::force_collection_config { collection, config };
+                let call_result = Self::dispatch_call(env, SystemOrigin::Root.into(), call)?;
+                return map_dispatch_result(call_result);
+            }
+            NftsFunc::ForceSetAttribute => {
+                if !C::TrustedContracts::contains(env.ext().address()) {
+                    return Ok(RetVal::Converging(NftsError::NoPermission as u32));
+                }
+                let input = Self::read_unbounded_input::<
+                    _,
+                    ForceSetAttributeInput<T, Vec<u8>, Vec<u8>>,
+                    ForceSetAttributeInput<
+                        T,
+                        BoundedVec<u8, T::KeyLimit>,
+                        BoundedVec<u8, T::ValueLimit>,
+                    >,
+                >(env)?;
+                let (set_as, collection, maybe_item, namespace, key, value) = match input {
+                    Ok(input) => input,
+                    Err(e) => return Ok(RetVal::Converging(e as u32)),
+                };
+                let (key, value) = match (bounded(key), bounded(value)) {
+                    (Ok(key), Ok(value)) => (key, value),
+                    _ => return Ok(RetVal::Converging(NftsError::InputTooLarge as u32)),
+                };
+                env.charge_weight(C::WeightInfo::force_set_attribute(
+                    key.len() as u32,
+                    value.len() as u32,
+                ))?;
+                let set_as: Option<T::AccountId> = set_as.map(Into::into);
+                let AttributeNamespaceWrapperFor::<T>(namespace) = namespace.into();
+
+                let call = pallet_nfts::Call::<T>::force_set_attribute {
+                    set_as,
+                    collection,
+                    maybe_item,
+                    namespace,
+                    key,
+                    value,
+                };
+                let call_result = Self::dispatch_call(env, SystemOrigin::Root.into(), call)?;
+                return map_dispatch_result(call_result);
+            }
+
+            NftsFunc::GetCollection => {
+                let id: T::CollectionId = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::get_collection())?;
 
                 let collection_details = pallet_nfts::Collection::<T>::get(id);
-                env.write(&collection_details.encode(), false, None)?;
+                Self::write_output(env, dry_run_output, &collection_details)?;
+            }
+            NftsFunc::GetOwnedItems => {
+                let (account, collection, cursor, limit): OwnedItemsInput<T> =
+                    Self::read_input(env)?;
+                let account: T::AccountId = account.into();
+                let limit = limit.min(MAX_PAGE_SIZE);
+                if let (Some(collection), Some((cursor_collection, _))) = (collection, cursor) {
+                    if cursor_collection != collection {
+                        return Ok(RetVal::Converging(NftsError::InvalidInput as u32));
+                    }
+                }
+
+                // Charge for every requested item, the ones not found are refunded below.
+                let charged = env.charge_weight(C::WeightInfo::get_owned_items(limit))?;
+
+                let items: Vec<(T::CollectionId, T::ItemId)> = match (collection, cursor) {
+                    (Some(collection), Some((_, item))) => {
+                        pallet_nfts::Account::<T>::iter_key_prefix_from(
+                            (&account, collection),
+                            pallet_nfts::Account::<T>::hashed_key_for((&account, collection, item)),
+                        )
+                        .map(|item| (collection, item))
+                        .take(limit as usize)
+                        .collect()
+                    }
+                    (Some(collection), None) => {
+                        pallet_nfts::Account::<T>::iter_key_prefix((&account, collection))
+                            .map(|item| (collection, item))
+                            .take(limit as usize)
+                            .collect()
+                    }
+                    (None, Some((collection, item))) => {
+                        pallet_nfts::Account::<T>::iter_key_prefix_from(
+                            (&account,),
+                            pallet_nfts::Account::<T>::hashed_key_for((&account, collection, item)),
+                        )
+                        .take(limit as usize)
+                        .collect()
+                    }
+                    (None, None) => pallet_nfts::Account::<T>::iter_key_prefix((&account,))
+                        .take(limit as usize)
+                        .collect(),
+                };
+                env.adjust_weight(charged, C::WeightInfo::get_owned_items(items.len() as u32));
+
+                Self::write_output(env, dry_run_output, &into_page(items, limit))?;
+            }
+            NftsFunc::GetCollections => {
+                let (cursor, limit): (Option<T::CollectionId>, u32) = Self::read_input(env)?;
+                let limit = limit.min(MAX_PAGE_SIZE);
+
+                // Charge for every requested collection, the ones not found are refunded below.
+                let charged = env.charge_weight(C::WeightInfo::get_collections(limit))?;
+
+                let collections: Vec<T::CollectionId> = match cursor {
+                    Some(cursor) => pallet_nfts::Collection::<T>::iter_keys_from(
+                        pallet_nfts::Collection::<T>::hashed_key_for(cursor),
+                    )
+                    .take(limit as usize)
+                    .collect(),
+                    None => pallet_nfts::Collection::<T>::iter_keys()
+                        .take(limit as usize)
+                        .collect(),
+                };
+                env.adjust_weight(
+                    charged,
+                    C::WeightInfo::get_collections(collections.len() as u32),
+                );
+
+                Self::write_output(env, dry_run_output, &into_page(collections, limit))?;
+            }
+            NftsFunc::GetOwnedCollections => {
+                let (owner, cursor, limit): ([u8; 32], Option<T::CollectionId>, u32) =
+                    Self::read_input(env)?;
+                let owner: T::AccountId = owner.into();
+                let limit = limit.min(MAX_PAGE_SIZE);
+
+                // Charge for every requested collection, the ones not found are refunded below.
+                let charged = env.charge_weight(C::WeightInfo::get_owned_collections(limit))?;
+
+                let collections: Vec<T::CollectionId> = match cursor {
+                    Some(cursor) => pallet_nfts::CollectionAccount::<T>::iter_key_prefix_from(
+                        &owner,
+                        pallet_nfts::CollectionAccount::<T>::hashed_key_for(&owner, cursor),
+                    )
+                    .take(limit as usize)
+                    .collect(),
+                    None => pallet_nfts::CollectionAccount::<T>::iter_key_prefix(&owner)
+                        .take(limit as usize)
+                        .collect(),
+                };
+                env.adjust_weight(
+                    charged,
+                    C::WeightInfo::get_owned_collections(collections.len() as u32),
+                );
+
+                Self::write_output(env, dry_run_output, &into_page(collections, limit))?;
+            }
+            NftsFunc::GetCollectionConfig => {
+                let collection: T::CollectionId = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::get_collection_config())?;
+
+                let config = pallet_nfts::CollectionConfigOf::<T>::get(collection)
+                    .map(|config| CollectionConfigExtWrapperFor::<T>::from(config).0);
+                Self::write_output(env, dry_run_output, &config)?;
+            }
+            NftsFunc::GetItemConfig => {
+                let (collection, item): (T::CollectionId, T::ItemId) = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::get_item_config())?;
+
+                let settings =
+                    pallet_nfts::ItemConfigOf::<T>::get(collection, item).map(|config| {
+                        ItemSettingsExt::from(ItemSettingsFlagsWrapper::from(config.settings).0)
+                    });
+                Self::write_output(env, dry_run_output, &settings)?;
+            }
+            NftsFunc::GetCapabilities => {
+                env.charge_weight(C::WeightInfo::get_capabilities())?;
+                Self::write_output(env, dry_run_output, &capabilities::<T>())?;
+            }
+            NftsFunc::GetItemOwner => {
+                let (collection, item): (T::CollectionId, T::ItemId) = Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::get_item_owner())?;
+
+                let owner =
+                    <pallet_nfts::Pallet<T> as Inspect<T::AccountId>>::owner(&collection, &item);
+                Self::write_output(env, dry_run_output, &owner)?;
+            }
+            NftsFunc::GetBalance => {
+                let (collection, owner): (T::CollectionId, [u8; 32]) = Self::read_input(env)?;
+                let owner: T::AccountId = owner.into();
+
+                // The items are counted by pages, each one charged before it's read so that a
+                // large balance can't be counted for free. The unread part of the last page is
+                // refunded.
+                let mut items = pallet_nfts::Account::<T>::iter_key_prefix((&owner, collection));
+                let mut balance: u32 = 0;
+                loop {
+                    let charged = env.charge_weight(C::WeightInfo::get_balance(MAX_PAGE_SIZE))?;
+                    let counted = items.by_ref().take(MAX_PAGE_SIZE as usize).count() as u32;
+                    balance = balance.saturating_add(counted);
+                    if counted < MAX_PAGE_SIZE {
+                        env.adjust_weight(charged, C::WeightInfo::get_balance(counted));
+                        break;
+                    }
+                }
+                Self::write_output(env, dry_run_output, &balance)?;
+            }
+            NftsFunc::GetApproval => {
+                let (collection, item, delegate): (T::CollectionId, T::ItemId, [u8; 32]) =
+                    Self::read_input(env)?;
+                env.charge_weight(C::WeightInfo::get_approval())?;
+                let delegate: T::AccountId = delegate.into();
+
+                // `None` when the delegate isn't approved, `Some(None)` for an approval without
+                // deadline.
+                let approval = item_details::<T>(collection, item)
+                    .and_then(|details| details.approvals.get(&delegate).copied());
+                Self::write_output(env, dry_run_output, &approval)?;
+            }
+            NftsFunc::GetAttribute => {
+                let input = Self::read_unbounded_input::<
+                    _,
+                    GetAttributeInput<T, Vec<u8>>,
+                    GetAttributeInput<T, BoundedVec<u8, T::KeyLimit>>,
+                >(env)?;
+                let (collection, maybe_item, namespace, key) = match input {
+                    Ok(input) => input,
+                    Err(e) => return Ok(RetVal::Converging(e as u32)),
+                };
+                let key: BoundedVec<u8, T::KeyLimit> = match bounded(key) {
+                    Ok(key) => key,
+                    Err(e) => return Ok(RetVal::Converging(e as u32)),
+                };
+                env.charge_weight(C::WeightInfo::get_attribute(key.len() as u32))?;
+                let AttributeNamespaceWrapperFor::<T>(namespace) = namespace.into();
+
+                let value =
+                    pallet_nfts::Attribute::<T>::get((collection, maybe_item, namespace, key))
+                        .map(|(value, _)| value.into_inner());
+                let output: Result<Option<Vec<u8>>, NftsError> = Ok(value);
+                Self::write_output(env, dry_run_output, &output)?;
             }
 
             // Constants
             NftsFunc::GetApprovalsLimit => {
-                let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
-                env.charge_weight(base_weight)?;
-
+                env.charge_weight(C::WeightInfo::get_constant())?;
                 let x = T::ApprovalsLimit::get();
-                env.write(&x.encode(), false, None)?;
+                Self::write_output(env, dry_run_output, &x)?;
             }
             NftsFunc::GetAttributeDepositBase => {
-                let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
-                env.charge_weight(base_weight)?;
-
+                env.charge_weight(C::WeightInfo::get_constant())?;
                 let x = T::AttributeDepositBase::get();
-                env.write(&x.encode(), false, None)?;
+                Self::write_output(env, dry_run_output, &x)?;
             }
             NftsFunc::GetCollectionDeposit => {
-                let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
-                env.charge_weight(base_weight)?;
-
+                env.charge_weight(C::WeightInfo::get_constant())?;
                 let x = T::CollectionDeposit::get();
-                env.write(&x.encode(), false, None)?;
+                Self::write_output(env, dry_run_output, &x)?;
             }
             NftsFunc::GetDepositPerByte => {
-                let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
-                env.charge_weight(base_weight)?;
-
+                env.charge_weight(C::WeightInfo::get_constant())?;
                 let x = <T as pallet_nfts::Config>::DepositPerByte::get();
-                env.write(&x.encode(), false, None)?;
+                Self::write_output(env, dry_run_output, &x)?;
+            }
+            NftsFunc::GetNftsConstants => {
+                env.charge_weight(C::WeightInfo::get_nfts_constants())?;
+                Self::write_output(env, dry_run_output, &nfts_constants::<T>())?;
             }
         };
 
-        Ok(RetVal::Converging(NftsError::Success as u32))
+        Ok(RetVal::Converging(SUCCESS))
+    }
+}
+
+impl<T, C> NftsExtension<T, C>
+where
+    T: pallet_contracts::Config + pallet_nfts::Config,
+    C: NftsExtensionConfig<T>,
+{
+    /// Charge the decoding overhead of the call input, then read and decode it. Only the input is
+    /// read: `read_as` reads `V::max_encoded_len()` bytes and fails on the shorter encodings.
+    fn read_input<E, V>(env: &mut Environment<E, BufInBufOutState>) -> Result<V, DispatchError>
+    where
+        E: Ext<T = T>,
+        V: Decode + MaxEncodedLen + Debug,
+    {
+        let len = env.in_len();
+        if len as usize > V::max_encoded_len() {
+            return Err(pallet_contracts::Error::<T>::DecodingFailed.into());
+        }
+        env.charge_weight(C::WeightInfo::read_input(len))?;
+        let input = env.read_as_unbounded(len)?;
+        log::trace!(target: LOG_TARGET, "input: {:?}", input);
+        Ok(input)
+    }
+
+    /// Charge the copy of an output to the contract memory by its length, then write it. In a dry
+    /// run the output is kept in `dry_run_output` instead, to be returned in `DryRunResultExt`.
+    fn write_output<E, V>(
+        env: &mut Environment<E, BufInBufOutState>,
+        dry_run_output: &mut Option<Vec<u8>>,
+        output: &V,
+    ) -> Result<(), DispatchError>
+    where
+        E: Ext<T = T>,
+        V: Encode,
+    {
+        let output = output.encode();
+        env.charge_weight(C::WeightInfo::write_output(output.len() as u32))?;
+        match dry_run_output {
+            Some(buffer) => {
+                *buffer = output;
+                Ok(())
+            }
+            None => env.write(&output, false, None),
+        }
+    }
+
+    /// Read an input holding byte vectors that can't be bounded at decoding time. `Bounded` is the
+    /// same input with its vectors bounded by the pallet limits: a longer input is rejected with
+    /// `InputTooLarge` before anything is read, otherwise its length is charged and it's decoded.
+    fn read_unbounded_input<E, V, Bounded>(
+        env: &mut Environment<E, BufInBufOutState>,
+    ) -> Result<Result<V, NftsError>, DispatchError>
+    where
+        E: Ext<T = T>,
+        V: Decode + Debug,
+        Bounded: MaxEncodedLen,
+    {
+        let len = env.in_len();
+        if len as usize > Bounded::max_encoded_len() {
+            log::debug!(target: LOG_TARGET, "input of {} bytes rejected", len);
+            return Ok(Err(NftsError::InputTooLarge));
+        }
+        env.charge_weight(C::WeightInfo::read_input(len))?;
+        let input = env.read_as_unbounded(len)?;
+        log::trace!(target: LOG_TARGET, "input: {:?}", input);
+        Ok(Ok(input))
+    }
+
+    /// Dispatch a call of the nfts pallet. Its declared weight is charged up front and the unused
+    /// part is refunded once the actual weight is known from the post dispatch info. The error of
+    /// a failed call is appended to the debug buffer of the contract, when it's enabled.
+    fn dispatch_call<E: Ext<T = T>>(
+        env: &mut Environment<E, BufInBufOutState>,
+        origin: <T as SysConfig>::RuntimeOrigin,
+        call: pallet_nfts::Call<T>,
+    ) -> Result<DispatchResult, DispatchError> {
+        let info = call.get_dispatch_info();
+        let name = call.get_call_name();
+        let charged = env.charge_weight(info.weight)?;
+        let result = call.dispatch_bypass_filter(origin);
+        env.adjust_weight(charged, extract_actual_weight(&result, &info));
+
+        if let Err(e) = &result {
+            log::debug!(target: LOG_TARGET, "pallet_nfts::{} failed: {:?}", name, e.error);
+            env.ext().append_debug_buffer(&format!(
+                "NftsExtension: pallet_nfts::{} failed: {:?}",
+                name, e.error
+            ));
+        }
+        Ok(result.map(|_| ()).map_err(|e| e.error))
+    }
+
+    /// Dispatch the calls of a batch, each one charged as by `dispatch_call`. In atomic mode the
+    /// batch stops on the first failure, which is returned after everything is rolled back. Its
+    /// index is dropped, the error being a bare status code, a best effort dry run reports it. In
+    /// best effort mode every call is dispatched, the pallet calls being transactional a failed
+    /// one leaves no change behind. The statuses of the calls are written to the output.
+    fn dispatch_batch<E: Ext<T = T>>(
+        env: &mut Environment<E, BufInBufOutState>,
+        dry_run_output: &mut Option<Vec<u8>>,
+        origin: <T as SysConfig>::RuntimeOrigin,
+        calls: Vec<pallet_nfts::Call<T>>,
+        mode: BatchModeExt,
+    ) -> Result<RetVal, DispatchError> {
+        let statuses: Vec<u32> = match mode {
+            BatchModeExt::Atomic => {
+                let len = calls.len();
+                let batch_result = with_transaction(|| {
+                    for call in calls {
+                        match Self::dispatch_call(env, origin.clone(), call) {
+                            Ok(Ok(())) => (),
+                            Ok(Err(e)) => return TransactionOutcome::Rollback(Ok(Err(e))),
+                            Err(e) => return TransactionOutcome::Rollback(Err(e)),
+                        }
+                    }
+                    TransactionOutcome::Commit(Ok(Ok(())))
+                })?;
+                if batch_result.is_err() {
+                    return map_dispatch_result(batch_result);
+                }
+                vec![SUCCESS; len]
+            }
+            BatchModeExt::BestEffort => calls
+                .into_iter()
+                .map(
+                    |call| match Self::dispatch_call(env, origin.clone(), call)? {
+                        Ok(()) => Ok(SUCCESS),
+                        Err(e) => Ok(nfts_error(e) as u32),
+                    },
+                )
+                .collect::<Result<_, DispatchError>>()?,
+        };
+
+        let output: Result<Vec<u32>, NftsError> = Ok(statuses);
+        Self::write_output(env, dry_run_output, &output)?;
+        Ok(RetVal::Converging(SUCCESS))
+    }
+}
+
+/// Gather all the constants of the nfts pallet.
+fn nfts_constants<T: pallet_nfts::Config>() -> NftsConstantsExt<NftsBalanceOf<T>, T::BlockNumber> {
+    let PalletFeaturesFlagsWrapper(features) = T::Features::get().into();
+    NftsConstantsExt {
+        collection_deposit: T::CollectionDeposit::get(),
+        item_deposit: T::ItemDeposit::get(),
+        metadata_deposit_base: T::MetadataDepositBase::get(),
+        attribute_deposit_base: T::AttributeDepositBase::get(),
+        deposit_per_byte: <T as pallet_nfts::Config>::DepositPerByte::get(),
+        string_limit: T::StringLimit::get(),
+        key_limit: T::KeyLimit::get(),
+        value_limit: T::ValueLimit::get(),
+        approvals_limit: T::ApprovalsLimit::get(),
+        item_attributes_approvals_limit: T::ItemAttributesApprovalsLimit::get(),
+        max_tips: T::MaxTips::get(),
+        max_deadline_duration: T::MaxDeadlineDuration::get(),
+        max_attributes_per_call: T::MaxAttributesPerCall::get(),
+        features: features.into(),
+    }
+}
+
+/// Describe the functions served by the extension and the pallet features enabled.
+fn capabilities<T: pallet_nfts::Config>() -> CapabilitiesExt {
+    let PalletFeaturesFlagsWrapper(features) = T::Features::get().into();
+    CapabilitiesExt {
+        version: VERSION,
+        functions: FUNCTIONS
+            .iter()
+            .map(|function| func_id(VERSION, *function))
+            .collect(),
+        features: features.into(),
+    }
+}
+
+/// Bound a byte vector of the input by one of the pallet limits.
+fn bounded<S: Get<u32>>(bytes: Vec<u8>) -> Result<BoundedVec<u8, S>, NftsError> {
+    BoundedVec::try_from(bytes).map_err(|_| NftsError::InputTooLarge)
+}
+
+/// Build the page of a query limited to `limit` entries. The last entry is used as the cursor of
+/// the next page unless fewer entries than requested were found.
+fn into_page<Item: Clone>(items: Vec<Item>, limit: u32) -> Page<Item, Item> {
+    let next = if items.len() as u32 == limit {
+        items.last().cloned()
+    } else {
+        None
+    };
+    Page { items, next }
+}
+
+/// Map the result of a pallet call to the status code returned to the contract.
+fn map_dispatch_result(call_result: DispatchResult) -> Result<RetVal, DispatchError> {
+    match call_result {
+        Err(e) => {
+            let mapped_error = nfts_error(e);
+            log::debug!(target: LOG_TARGET, "{:?} mapped to {:?}", e, mapped_error);
+            Ok(RetVal::Converging(mapped_error as u32))
+        }
+        Ok(_) => Ok(RetVal::Converging(SUCCESS)),
     }
 }
 
@@ -156,6 +1056,88 @@ struct CreateInput<Price, BlockNumber, CollectionId> {
     pub config: CollectionConfigExt<Price, BlockNumber, CollectionId>,
 }
 
+/// Input of `get_owned_items`: the account, the collection, the cursor and the limit.
+type OwnedItemsInput<T> = (
+    [u8; 32],
+    Option<<T as pallet_nfts::Config>::CollectionId>,
+    Option<(
+        <T as pallet_nfts::Config>::CollectionId,
+        <T as pallet_nfts::Config>::ItemId,
+    )>,
+    u32,
+);
+
+/// Input of `force_set_attribute`, generic over the types of the attribute key and value.
+type ForceSetAttributeInput<T, Key, Value> = (
+    Option<[u8; 32]>,
+    <T as pallet_nfts::Config>::CollectionId,
+    Option<<T as pallet_nfts::Config>::ItemId>,
+    AttributeNamespaceExt<[u8; 32]>,
+    Key,
+    Value,
+);
+
+/// Input of `get_attribute`, generic over the type of the attribute key.
+type GetAttributeInput<T, Key> = (
+    <T as pallet_nfts::Config>::CollectionId,
+    Option<<T as pallet_nfts::Config>::ItemId>,
+    AttributeNamespaceExt<[u8; 32]>,
+    Key,
+);
+
+/// Same fields as `pallet_nfts::ItemDetails`, which are private to the pallet. The whole value of
+/// `pallet_nfts::Item` is decoded into it, the `tests` module checks the layouts match.
+#[derive(Decode)]
+#[cfg_attr(test, derive(scale_info::TypeInfo))]
+struct ItemDetailsMirror<AccountId, Deposit, Approvals> {
+    #[allow(dead_code)]
+    owner: AccountId,
+    approvals: Approvals,
+    #[allow(dead_code)]
+    deposit: Deposit,
+}
+
+type ItemDetailsMirrorFor<T> = ItemDetailsMirror<
+    <T as frame_system::Config>::AccountId,
+    pallet_nfts::ItemDeposit<NftsBalanceOf<T>, <T as frame_system::Config>::AccountId>,
+    BoundedBTreeMap<
+        <T as frame_system::Config>::AccountId,
+        Option<<T as frame_system::Config>::BlockNumber>,
+        <T as pallet_nfts::Config>::ApprovalsLimit,
+    >,
+>;
+
+/// Details of an item read from `pallet_nfts::Item`, with its approvals.
+fn item_details<T: pallet_nfts::Config>(
+    collection: T::CollectionId,
+    item: T::ItemId,
+) -> Option<ItemDetailsMirrorFor<T>> {
+    let details = pallet_nfts::Item::<T>::get(collection, item)?;
+    ItemDetailsMirrorFor::<T>::decode_all(&mut &details.encode()[..]).ok()
+}
+
+/// Entry of `batch_mint`: the item, its owner and the mint witness.
+type BatchMintEntry<T> = (
+    <T as pallet_nfts::Config>::ItemId,
+    [u8; 32],
+    Option<MintWitnessExt<<T as pallet_nfts::Config>::ItemId>>,
+);
+/// Input of `batch_mint`, generic over the type of the entries.
+type BatchMintInput<T, Entries> = (
+    <T as pallet_nfts::Config>::CollectionId,
+    Entries,
+    BatchModeExt,
+);
+
+/// Entry of `batch_transfer`: the collection, the item and its new owner.
+type BatchTransferEntry<T> = (
+    <T as pallet_nfts::Config>::CollectionId,
+    <T as pallet_nfts::Config>::ItemId,
+    [u8; 32],
+);
+/// Input of `batch_transfer`, generic over the type of the entries.
+type BatchTransferInput<Entries> = (Entries, BatchModeExt);
+
 type NftsBalanceOf<T> = <<T as pallet_nfts::Config>::Currency as Currency<
     <T as frame_system::Config>::AccountId,
 >>::Balance;
@@ -175,22 +1157,7 @@ type CollectionConfigExtFor<T> = CollectionConfigExt<
 struct CollectionConfigWrapperFor<T: pallet_nfts::Config>(CollectionConfigFor<T>);
 impl<T: pallet_nfts::Config> From<CollectionConfigExtFor<T>> for CollectionConfigWrapperFor<T> {
     fn from(value: CollectionConfigExtFor<T>) -> Self {
-        let mut settings = CollectionSettings::default();
-        if value.setting.transferable_items {
-            settings.0.insert(CollectionSetting::TransferableItems)
-        }
-        if value.setting.unlocked_metadata {
-            settings.0.insert(CollectionSetting::UnlockedMetadata)
-        }
-        if value.setting.unlocked_attributes {
-            settings.0.insert(CollectionSetting::UnlockedAttributes)
-        }
-        if value.setting.unlocked_max_supply {
-            settings.0.insert(CollectionSetting::UnlockedMaxSupply)
-        }
-        if value.setting.deposit_required {
-            settings.0.insert(CollectionSetting::DepositRequired)
-        }
+        let CollectionSettingsWrapper(settings) = value.setting.into();
 
         let mut mint_settings = MintSettings::<
             <<T as pallet_nfts::Config>::Currency as Currency<
@@ -199,11 +1166,11 @@ impl<T: pallet_nfts::Config> From<CollectionConfigExtFor<T>> for CollectionConfi
             T::BlockNumber,
             T::CollectionId,
         >::default();
-        // mint_settings.mint_type = match value.mint_settings.mint_type {
-        //     MintTypeExt::Issuer => MintType::Issuer,
-        //     MintTypeExt::Public => MintType::Public,
-        //     MintTypeExt::HolderOf(_) => MintType::HolderOf(T::CollectionId::initial_value()),
-        // };
+        mint_settings.mint_type = match value.mint_settings.mint_type {
+            MintTypeExt::Issuer => MintType::Issuer,
+            MintTypeExt::Public => MintType::Public,
+            MintTypeExt::HolderOf(id) => MintType::HolderOf(id),
+        };
         mint_settings.price = match value.mint_settings.price {
             Some(x) => Some(x.saturated_into()),
             None => None,
@@ -216,28 +1183,9 @@ impl<T: pallet_nfts::Config> From<CollectionConfigExtFor<T>> for CollectionConfi
             Some(x) => Some(x.saturated_into()),
             None => None,
         };
-        if value.mint_settings.default_item_settings.transferable {
-            mint_settings
-                .default_item_settings
-                .0
-                .insert(ItemSetting::Transferable)
-        }
-        if value.mint_settings.default_item_settings.unlocked_metadata {
-            mint_settings
-                .default_item_settings
-                .0
-                .insert(ItemSetting::UnlockedMetadata)
-        }
-        if value
-            .mint_settings
-            .default_item_settings
-            .unlocked_attributes
-        {
-            mint_settings
-                .default_item_settings
-                .0
-                .insert(ItemSetting::UnlockedAttributes)
-        }
+        let ItemSettingsWrapper(default_item_settings) =
+            value.mint_settings.default_item_settings.into();
+        mint_settings.default_item_settings = default_item_settings;
 
         let config = CollectionConfigFor::<T> {
             settings,
@@ -247,3 +1195,51 @@ impl<T: pallet_nfts::Config> From<CollectionConfigExtFor<T>> for CollectionConfi
         CollectionConfigWrapperFor(config)
     }
 }
+
+/// Wrapper to convert the extension attribute namespace to the one of the nfts pallet
+struct AttributeNamespaceWrapperFor<T: pallet_nfts::Config>(AttributeNamespace<T::AccountId>);
+impl<T: pallet_nfts::Config> From<AttributeNamespaceExt<[u8; 32]>>
+    for AttributeNamespaceWrapperFor<T>
+where
+    <T as SysConfig>::AccountId: From<[u8; 32]>,
+{
+    fn from(value: AttributeNamespaceExt<[u8; 32]>) -> Self {
+        let namespace = match value {
+            AttributeNamespaceExt::Pallet => AttributeNamespace::Pallet,
+            AttributeNamespaceExt::CollectionOwner => AttributeNamespace::CollectionOwner,
+            AttributeNamespaceExt::ItemOwner => AttributeNamespace::ItemOwner,
+            AttributeNamespaceExt::Account(account) => AttributeNamespace::Account(account.into()),
+        };
+        AttributeNamespaceWrapperFor(namespace)
+    }
+}
+
+/// Wrapper to implement From trait and convert original types of the nfts pallet to Extension types
+struct CollectionConfigExtWrapperFor<T: pallet_nfts::Config>(CollectionConfigExtFor<T>);
+impl<T: pallet_nfts::Config> From<CollectionConfigFor<T>> for CollectionConfigExtWrapperFor<T> {
+    fn from(value: CollectionConfigFor<T>) -> Self {
+        let CollectionSettingsFlagsWrapper(setting) = value.settings.into();
+
+        let mint_type = match value.mint_settings.mint_type {
+            MintType::Issuer => MintTypeExt::Issuer,
+            MintType::Public => MintTypeExt::Public,
+            MintType::HolderOf(id) => MintTypeExt::HolderOf(id),
+        };
+        let ItemSettingsFlagsWrapper(default_item_settings) =
+            value.mint_settings.default_item_settings.into();
+        let mint_settings = MintSettingsExt {
+            mint_type,
+            price: value.mint_settings.price,
+            start_block: value.mint_settings.start_block,
+            end_block: value.mint_settings.end_block,
+            default_item_settings: default_item_settings.into(),
+        };
+
+        let config = CollectionConfigExtFor::<T> {
+            setting: setting.into(),
+            max_supply: value.max_supply,
+            mint_settings,
+        };
+        CollectionConfigExtWrapperFor(config)
+    }
+}
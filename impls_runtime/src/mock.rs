@@ -0,0 +1,297 @@
+//! Runtime of the tests, with the extension registered in `pallet_contracts` and a fixture contract
+//! forwarding its calls to the extension.
+
+use crate::permissions::FilterCalls;
+use crate::{NftsExtension, NftsExtensionConfig, NftsFunc};
+use codec::Encode;
+use frame_support::parameter_types;
+use frame_support::traits::{
+    AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, Contains, Everything, IsInVec,
+    Nothing,
+};
+use frame_support::weights::Weight;
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_contracts::{
+    AddressGenerator, DefaultAddressGenerator, Determinism, Frame, RawOrigin, Schedule,
+};
+use pallet_contracts_primitives::{Code, ContractExecResult};
+use pallet_nfts::PalletFeatures;
+use sp_core::H256;
+use sp_runtime::generic;
+use sp_runtime::traits::{BlakeTwo256, Convert, IdentityLookup, Verify};
+use sp_runtime::{AccountId32, MultiSignature};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+type Header = generic::Header<BlockNumber, BlakeTwo256>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = generic::Block<Header, UncheckedExtrinsic>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        Randomness: pallet_insecure_randomness_collective_flip,
+        Nfts: pallet_nfts,
+        Contracts: pallet_contracts,
+    }
+);
+
+/// The pallet calls of the extension have the contract as signed origin.
+impl From<RawOrigin<AccountId>> for RuntimeOrigin {
+    fn from(origin: RawOrigin<AccountId>) -> Self {
+        match origin {
+            RawOrigin::Signed(account) | RawOrigin::Contract(account) => {
+                RuntimeOrigin::signed(account)
+            }
+        }
+    }
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type RuntimeCall = RuntimeCall;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU32<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type HoldIdentifier = ();
+    type MaxHolds = ();
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Test {}
+
+parameter_types! {
+    pub Features: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+// No deposit for the attributes, so that the owner deposit of a collection is the collection
+// deposit.
+impl pallet_nfts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type Locker = ();
+    type CollectionDeposit = ConstU128<100>;
+    type ItemDeposit = ConstU128<1>;
+    type MetadataDepositBase = ConstU128<1>;
+    type AttributeDepositBase = ConstU128<0>;
+    type DepositPerByte = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<50>;
+    type ValueLimit = ConstU32<50>;
+    type ApprovalsLimit = ConstU32<10>;
+    type ItemAttributesApprovalsLimit = ConstU32<2>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU32<10_000>;
+    type MaxAttributesPerCall = ConstU32<2>;
+    type Features = Features;
+    type OffchainSignature = Signature;
+    type OffchainPublic = AccountPublic;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+parameter_types! {
+    pub MySchedule: Schedule<Test> = Default::default();
+    pub DeletionWeightLimit: Weight = Weight::from_parts(500_000_000_000, 0);
+}
+
+impl Convert<Weight, Balance> for Test {
+    fn convert(weight: Weight) -> Balance {
+        weight.ref_time().into()
+    }
+}
+
+/// Deploys each contract at the address given as its salt, so that the tests can name them.
+pub struct SaltAddress;
+
+impl AddressGenerator<Test> for SaltAddress {
+    fn contract_address(_: &AccountId, _: &H256, _: &[u8], salt: &[u8]) -> AccountId {
+        let address: [u8; 32] = salt.try_into().expect("the salts are addresses");
+        address.into()
+    }
+
+    fn deposit_address(contract: &AccountId) -> AccountId {
+        <DefaultAddressGenerator as AddressGenerator<Test>>::deposit_address(contract)
+    }
+}
+
+/// Contract trusted with the privileged functions.
+pub const TRUSTED: [u8; 32] = [4; 32];
+/// Contract denied every function by the call filter.
+pub const FILTERED: [u8; 32] = [5; 32];
+
+parameter_types! {
+    pub Trusted: Vec<AccountId> = vec![TRUSTED.into()];
+}
+
+/// Lets every contract but `FILTERED` call the extension.
+pub struct NotFiltered;
+
+impl Contains<(AccountId, NftsFunc)> for NotFiltered {
+    fn contains((contract, _): &(AccountId, NftsFunc)) -> bool {
+        *contract != FILTERED.into()
+    }
+}
+
+/// Configuration of the extension in the test runtime.
+pub struct ExtensionConfig;
+
+impl NftsExtensionConfig<Test> for ExtensionConfig {
+    type TrustedContracts = IsInVec<Trusted>;
+    type CallFilter = FilterCalls<NotFiltered>;
+    type WeightInfo = ();
+}
+
+impl pallet_contracts::Config for Test {
+    type Time = Timestamp;
+    type Randomness = Randomness;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type CallFilter = Nothing;
+    type WeightPrice = Self;
+    type WeightInfo = ();
+    type ChainExtension = NftsExtension<Self, ExtensionConfig>;
+    type Schedule = MySchedule;
+    type CallStack = [Frame<Self>; 5];
+    type DeletionQueueDepth = ConstU32<128>;
+    type DeletionWeightLimit = DeletionWeightLimit;
+    type DepositPerByte = ConstU128<1>;
+    type DepositPerItem = ConstU128<1>;
+    type AddressGenerator = SaltAddress;
+    type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+    type MaxStorageKeyLen = ConstU32<128>;
+    type UnsafeUnstableInterface = ConstBool<false>;
+    type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::Config for Test {}
+
+/// Account deploying and calling the contracts.
+pub const DEPLOYER: [u8; 32] = [9; 32];
+
+const GAS_LIMIT: Weight = Weight::from_parts(100_000_000_000, 3 * 1024 * 1024);
+
+/// Contract calling the extension with the function id and the input it's called with.
+const FIXTURE: &str = include_str!("../fixtures/call_extension.wat");
+
+/// Externalities with the `accounts` and the deployer funded.
+pub fn new_test_ext(accounts: &[[u8; 32]]) -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .expect("valid genesis");
+    pallet_balances::GenesisConfig::<Test> {
+        balances: accounts
+            .iter()
+            .chain([DEPLOYER].iter())
+            .map(|account| ((*account).into(), 1_000_000_000_000))
+            .collect(),
+    }
+    .assimilate_storage(&mut storage)
+    .expect("valid balances");
+    storage.into()
+}
+
+/// Deploy the fixture contract at `address`.
+pub fn deploy(address: [u8; 32]) {
+    let wasm = wat::parse_str(FIXTURE).expect("valid fixture");
+    Contracts::bare_instantiate(
+        DEPLOYER.into(),
+        0,
+        GAS_LIMIT,
+        None,
+        Code::Upload(wasm),
+        vec![],
+        address.to_vec(),
+        false,
+    )
+    .result
+    .expect("fixture deployed");
+}
+
+/// Call the fixture contract at `contract`, forwarding `input` to the extension function.
+fn call_fixture(contract: [u8; 32], func_id: u32, input: &[u8]) -> ContractExecResult<Balance> {
+    let mut data = func_id.encode();
+    data.extend_from_slice(input);
+    Contracts::bare_call(
+        DEPLOYER.into(),
+        contract.into(),
+        0,
+        GAS_LIMIT,
+        None,
+        data,
+        false,
+        Determinism::Enforced,
+    )
+}
+
+/// Call the extension from the fixture contract at `contract`, returning the status code and the
+/// output written by the extension.
+pub fn call_extension(contract: [u8; 32], func_id: u32, input: &[u8]) -> (u32, Vec<u8>) {
+    let returned = call_fixture(contract, func_id, input)
+        .result
+        .expect("extension called");
+    let (status, output) = returned.data.split_at(4);
+    let status = u32::from_le_bytes(status.try_into().expect("4 bytes"));
+    (status, output.to_vec())
+}
+
+/// Weight consumed by a call of the extension from the fixture contract at `contract`, once
+/// refunded.
+pub fn extension_weight(contract: [u8; 32], func_id: u32, input: &[u8]) -> Weight {
+    call_fixture(contract, func_id, input).gas_consumed
+}
@@ -0,0 +1,56 @@
+//! Policies deciding which contracts may call which functions of the NFTs chain extension.
+
+use crate::NftsFunc;
+use frame_support::traits::Contains;
+use frame_support::weights::Weight;
+use sp_std::marker::PhantomData;
+
+/// Decides, per calling contract and per function, whether a call to the extension is allowed.
+/// A denied call returns `NoPermission` before its input is read.
+///
+/// Implement it directly for a storage-backed registry, so the weight of its reads is charged.
+pub trait CallFilter<AccountId> {
+    /// Whether `contract` may call `func`.
+    fn allows(contract: &AccountId, func: NftsFunc) -> bool;
+
+    /// Weight of an `allows` check, charged before it.
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Every contract may call every function.
+impl<AccountId> CallFilter<AccountId> for () {
+    fn allows(_contract: &AccountId, _func: NftsFunc) -> bool {
+        true
+    }
+}
+
+/// Only the contracts of `Allowed` may call the extension, for any function.
+pub struct AllowContracts<Allowed>(PhantomData<Allowed>);
+impl<AccountId, Allowed: Contains<AccountId>> CallFilter<AccountId> for AllowContracts<Allowed> {
+    fn allows(contract: &AccountId, _func: NftsFunc) -> bool {
+        Allowed::contains(contract)
+    }
+}
+
+/// Queries and constants are open to every contract, the extrinsics (including the privileged
+/// ones) are restricted to the contracts of `Allowed`.
+pub struct RestrictExtrinsics<Allowed>(PhantomData<Allowed>);
+impl<AccountId, Allowed: Contains<AccountId>> CallFilter<AccountId>
+    for RestrictExtrinsics<Allowed>
+{
+    fn allows(contract: &AccountId, func: NftsFunc) -> bool {
+        !func.is_extrinsic() || Allowed::contains(contract)
+    }
+}
+
+/// Any `Contains` filter of `(contract, function)` pairs.
+pub struct FilterCalls<Filter>(PhantomData<Filter>);
+impl<AccountId: Clone, Filter: Contains<(AccountId, NftsFunc)>> CallFilter<AccountId>
+    for FilterCalls<Filter>
+{
+    fn allows(contract: &AccountId, func: NftsFunc) -> bool {
+        Filter::contains(&(contract.clone(), func))
+    }
+}
@@ -0,0 +1,753 @@
+use crate::errors::{NftsError, SUCCESS};
+use crate::mock::{
+    call_extension, deploy, extension_weight, new_test_ext, Nfts, RuntimeOrigin, Test, FILTERED,
+    TRUSTED,
+};
+use crate::ItemDetailsMirror;
+use codec::{DecodeAll, Encode};
+use frame_support::traits::ConstU32;
+use frame_support::{assert_ok, BoundedBTreeMap};
+use nfts_extension_types::ids::{
+    dry_run_func_id, func_id, function, FUNCTIONS, LEGACY_VERSION, VERSION,
+};
+use nfts_extension_types::{
+    AttributeNamespaceExt, BatchModeExt, CapabilitiesExt, CollectionConfigExt, CreateInput,
+    DryRunResultExt, ItemSettingsExt, MintWitnessExt, NftsConstantsExt, Page, PalletFeaturesExt,
+    MAX_PAGE_SIZE, MAX_REDEPOSIT_ITEMS,
+};
+use pallet_nfts::{AttributeNamespace, CollectionConfig, CollectionSettings, MintSettings};
+use scale_info::{Type, TypeDef, TypeInfo};
+use sp_std::collections::btree_map::BTreeMap;
+
+type Approvals = BoundedBTreeMap<u64, Option<u64>, ConstU32<4>>;
+type Deposit = pallet_nfts::ItemDeposit<u128, u64>;
+
+/// Names and type names of the fields of a struct.
+fn fields(ty: Type) -> Vec<(Option<&'static str>, Option<&'static str>)> {
+    match ty.type_def {
+        TypeDef::Composite(composite) => composite
+            .fields
+            .into_iter()
+            .map(|field| (field.name, field.type_name))
+            .collect(),
+        _ => panic!("not a struct"),
+    }
+}
+
+#[test]
+fn item_details_mirror_has_the_pallet_layout() {
+    assert_eq!(
+        fields(ItemDetailsMirror::<u64, Deposit, Approvals>::type_info()),
+        fields(pallet_nfts::ItemDetails::<u64, Deposit, Approvals>::type_info()),
+    );
+
+    // An item owned by 1, with a deposit of 10 reserved from 1 and approved to 2 until 100.
+    let mut encoded = 1u64.encode();
+    let approvals = Approvals::try_from(BTreeMap::from([(2, Some(100))])).expect("within bound");
+    encoded.extend(approvals.encode());
+    encoded.extend((1u64, 10u128).encode());
+    let details =
+        pallet_nfts::ItemDetails::<u64, Deposit, Approvals>::decode_all(&mut &encoded[..])
+            .expect("valid item details");
+    let mirror =
+        ItemDetailsMirror::<u64, Deposit, Approvals>::decode_all(&mut &details.encode()[..])
+            .expect("same layout");
+    assert_eq!(mirror.approvals.get(&2), Some(&Some(100)));
+}
+
+/// Sample accounts of the vectors, `ALICE` and `BOB` of the client.
+const ALICE: [u8; 32] = [1; 32];
+const BOB: [u8; 32] = [2; 32];
+/// Contract owning nothing.
+const CHARLIE: [u8; 32] = [3; 32];
+
+/// Vectors of the client, checked against the runtime by `vectors_match_the_runtime`.
+const VECTORS: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../client/src/vectors/generated.rs"
+);
+
+/// A call of the vectors: the name of its constant, its doc comment, the contract calling, the
+/// function id and the input.
+struct Sample {
+    name: &'static str,
+    doc: &'static str,
+    caller: [u8; 32],
+    func_id: u32,
+    input: Vec<u8>,
+}
+
+fn sample(name: &'static str, doc: &'static str, function: u8, input: impl Encode) -> Sample {
+    Sample {
+        name,
+        doc,
+        caller: ALICE,
+        func_id: func_id(VERSION, function),
+        input: input.encode(),
+    }
+}
+
+fn samples() -> Vec<Sample> {
+    let create = CreateInput::<[u8; 32], u128, u32, u32> {
+        admin: ALICE,
+        config: CollectionConfigExt::default(),
+    };
+    let key = b"name".to_vec();
+    vec![
+        sample(
+            "CREATE",
+            "`create` by [`ALICE`](super::ALICE) with the default config, creating the \
+             collection `7`.",
+            function::CREATE,
+            create,
+        ),
+        Sample {
+            caller: CHARLIE,
+            ..sample(
+                "TRANSFER",
+                "`transfer` of the item `42` to [`BOB`](super::BOB) by a contract that isn't \
+                 allowed to, with no output.",
+                function::TRANSFER,
+                (3u32, 42u32, BOB),
+            )
+        },
+        sample(
+            "APPROVE_TRANSFER",
+            "`approve_transfer` of the item `42` to [`BOB`](super::BOB) until the block `100`, \
+             with no output.",
+            function::APPROVE_TRANSFER,
+            (3u32, 42u32, BOB, Some(100u32)),
+        ),
+        sample(
+            "BATCH_TRANSFER",
+            "`batch_transfer` in best effort mode of the items `42` to [`BOB`](super::BOB) and \
+             `44` to [`ALICE`](super::ALICE), the second one being unknown.",
+            function::BATCH_TRANSFER,
+            (
+                vec![(3u32, 42u32, BOB), (3u32, 44u32, ALICE)],
+                BatchModeExt::BestEffort,
+            ),
+        ),
+        sample(
+            "FORCE_SET_ATTRIBUTE",
+            "`force_set_attribute` of `k` to `v` in the pallet namespace of the collection, by a \
+             contract that isn't trusted.",
+            function::FORCE_SET_ATTRIBUTE,
+            (
+                None::<[u8; 32]>,
+                3u32,
+                None::<u32>,
+                AttributeNamespaceExt::<[u8; 32]>::Pallet,
+                b"k".to_vec(),
+                b"v".to_vec(),
+            ),
+        ),
+        sample(
+            "GET_COLLECTION",
+            "`get_collection` of a collection owned by [`ALICE`](super::ALICE), with a deposit \
+             of `100`, `2` items, `2` item configs and `1` attribute.",
+            function::GET_COLLECTION,
+            3u32,
+        ),
+        sample(
+            "GET_OWNED_ITEMS",
+            "`get_owned_items` of [`ALICE`](super::ALICE) in the collection, by pages of `2`: the \
+             items `42` and `43`, the last one being the cursor of the next page.",
+            function::GET_OWNED_ITEMS,
+            (ALICE, Some(3u32), None::<(u32, u32)>, 2u32),
+        ),
+        sample(
+            "GET_ITEM_OWNER",
+            "`get_item_owner` of the item `42`, owned by [`ALICE`](super::ALICE).",
+            function::GET_ITEM_OWNER,
+            (3u32, 42u32),
+        ),
+        sample(
+            "GET_BALANCE",
+            "`get_balance` of [`ALICE`](super::ALICE) in the collection, `2` items.",
+            function::GET_BALANCE,
+            (3u32, ALICE),
+        ),
+        sample(
+            "GET_APPROVAL",
+            "`get_approval` of [`BOB`](super::BOB) for the item `42`, approved until the block \
+             `100`.",
+            function::GET_APPROVAL,
+            (3u32, 42u32, BOB),
+        ),
+        sample(
+            "GET_ATTRIBUTE",
+            "`get_attribute` `name` of the item `42` set by the owner of the collection, `Song`.",
+            function::GET_ATTRIBUTE,
+            (
+                3u32,
+                Some(42u32),
+                AttributeNamespaceExt::<[u8; 32]>::CollectionOwner,
+                key,
+            ),
+        ),
+        Sample {
+            func_id: dry_run_func_id(VERSION, function::TRANSFER),
+            ..sample(
+                "DRY_RUN_TRANSFER",
+                "Dry run of the [`TRANSFER`] by a contract allowed to, it would succeed.",
+                function::TRANSFER,
+                (3u32, 42u32, BOB),
+            )
+        },
+    ]
+}
+
+/// State of the vectors: the contracts `ALICE` and `CHARLIE`, the collections `0` to `6` created
+/// by `ALICE`, and in the collection `3` the items `42` and `43` of `ALICE`, the attribute `name`
+/// of the item `42` and its approval to `BOB` until the block `100`.
+fn setup() {
+    deploy(ALICE);
+    deploy(CHARLIE);
+    let alice = RuntimeOrigin::signed(ALICE.into());
+    let config = CollectionConfig {
+        settings: CollectionSettings::all_enabled(),
+        max_supply: None,
+        mint_settings: MintSettings::default(),
+    };
+    for _ in 0..7 {
+        assert_ok!(Nfts::create(alice.clone(), ALICE.into(), config));
+    }
+    for item in [42, 43] {
+        assert_ok!(Nfts::mint(alice.clone(), 3, item, ALICE.into(), None));
+    }
+    assert_ok!(Nfts::set_attribute(
+        alice.clone(),
+        3,
+        Some(42),
+        AttributeNamespace::CollectionOwner,
+        b"name".to_vec().try_into().expect("within the key limit"),
+        b"Song".to_vec().try_into().expect("within the value limit"),
+    ));
+    assert_ok!(Nfts::approve_transfer(alice, 3, 42, BOB.into(), Some(100)));
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// A hex string literal, split in lines of 32 bytes indented by `indent`.
+fn hex_literal(bytes: &[u8], indent: usize) -> String {
+    let hex = hex(bytes);
+    let lines: Vec<&str> = hex
+        .as_bytes()
+        .chunks(64)
+        .map(|line| core::str::from_utf8(line).expect("hex is ascii"))
+        .collect();
+    format!("\"{}\"", lines.join(&format!("\\\n{}", " ".repeat(indent))))
+}
+
+/// Split `text` in lines of at most `width` characters.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![String::new()];
+    for word in text.split_whitespace() {
+        let line = lines.last_mut().expect("one line at least");
+        if !line.is_empty() && line.len() + 1 + word.len() > width {
+            lines.push(word.to_string());
+        } else {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+    }
+    lines
+}
+
+/// Source of the vectors of the client, for the status and output returned by the runtime.
+fn render(vectors: &[(Sample, u32, Vec<u8>)]) -> String {
+    let mut source = String::from(
+        "//! Generated by the `vectors_match_the_runtime` test of the runtime extension, don't \
+         edit.\n//! Regenerate with `UPDATE_VECTORS=1 cargo test -p nfts-extension-impls-runtime \
+         vectors`.\n\nuse super::Vector;\n",
+    );
+    for (sample, status, output) in vectors {
+        source.push('\n');
+        for line in wrap(sample.doc, 96) {
+            source.push_str(&format!("/// {}\n", line));
+        }
+        source.push_str(&format!(
+            "pub const {}: Vector = Vector {{\n    func_id: {:#06x}_{:04x},\n    input: {},\n    \
+             status: {},\n    output: {},\n}};\n",
+            sample.name,
+            sample.func_id >> 16,
+            sample.func_id & 0xffff,
+            hex_literal(&sample.input, 12),
+            status,
+            hex_literal(output, 13),
+        ));
+    }
+    source
+}
+
+#[test]
+fn vectors_match_the_runtime() {
+    let vectors: Vec<_> = samples()
+        .into_iter()
+        .map(|sample| {
+            let (status, output) = new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+                setup();
+                call_extension(sample.caller, sample.func_id, &sample.input)
+            });
+            (sample, status, output)
+        })
+        .collect();
+    let source = render(&vectors);
+
+    if std::env::var_os("UPDATE_VECTORS").is_some() {
+        std::fs::write(VECTORS, source).expect("vectors written");
+    } else {
+        let current = std::fs::read_to_string(VECTORS).expect("vectors read");
+        assert!(
+            current == source,
+            "the vectors of the client are out of date, run this test with UPDATE_VECTORS=1"
+        );
+    }
+}
+
+#[test]
+fn create_ignores_the_input_config() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        let input = CreateInput::<[u8; 32], u128, u32, u32> {
+            admin: ALICE,
+            config: CollectionConfigExt::fully_locked(),
+        };
+        assert_eq!(
+            call_extension(ALICE, func_id(VERSION, function::CREATE), &input.encode()),
+            (SUCCESS, Ok::<u32, NftsError>(0).encode())
+        );
+        let default_config = CollectionConfig {
+            settings: Default::default(),
+            max_supply: None,
+            mint_settings: Default::default(),
+        };
+        assert_eq!(
+            pallet_nfts::CollectionConfigOf::<Test>::get(0),
+            Some(default_config)
+        );
+    });
+}
+
+#[test]
+fn force_functions_require_a_trusted_contract() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        deploy(TRUSTED);
+        let force_create = func_id(VERSION, function::FORCE_CREATE);
+        let input = (BOB, CollectionConfigExt::<u128, u32, u32>::fully_locked()).encode();
+
+        // The trust is checked before the input is read.
+        assert_eq!(
+            call_extension(ALICE, force_create, &[]),
+            (NftsError::NoPermission as u32, vec![])
+        );
+        assert_eq!(
+            call_extension(ALICE, force_create, &input),
+            (NftsError::NoPermission as u32, vec![])
+        );
+        assert_eq!(Nfts::collection_owner(0), None);
+
+        assert_eq!(
+            call_extension(TRUSTED, force_create, &input),
+            (SUCCESS, Ok::<u32, NftsError>(0).encode())
+        );
+        assert_eq!(Nfts::collection_owner(0), Some(BOB.into()));
+        let force_owner = func_id(VERSION, function::FORCE_COLLECTION_OWNER);
+        assert_eq!(
+            call_extension(ALICE, force_owner, &(0u32, ALICE).encode()),
+            (NftsError::NoPermission as u32, vec![])
+        );
+        assert_eq!(
+            call_extension(TRUSTED, force_owner, &(0u32, ALICE).encode()),
+            (SUCCESS, vec![])
+        );
+        assert_eq!(Nfts::collection_owner(0), Some(ALICE.into()));
+    });
+}
+
+#[test]
+fn redeposit_is_limited_in_items() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        let redeposit = func_id(VERSION, function::REDEPOSIT);
+        assert_eq!(
+            call_extension(ALICE, redeposit, &(3u32, vec![42u32, 43]).encode()),
+            (SUCCESS, vec![])
+        );
+        assert_eq!(
+            call_extension(CHARLIE, redeposit, &(3u32, vec![42u32]).encode()),
+            (NftsError::NoPermission as u32, vec![])
+        );
+        let items: Vec<u32> = (0..=MAX_REDEPOSIT_ITEMS).collect();
+        assert_eq!(
+            call_extension(ALICE, redeposit, &(3u32, items).encode()),
+            (NftsError::InputTooLarge as u32, vec![])
+        );
+    });
+}
+
+/// Page of `(collection, item)` ids with a `(collection, item)` cursor.
+type ItemsPage = Page<(u32, u32), (u32, u32)>;
+
+/// The page of `get_owned_items` of `ALICE` in `collection` from `cursor`.
+fn owned_items(
+    collection: Option<u32>,
+    cursor: Option<(u32, u32)>,
+    limit: u32,
+) -> (u32, Option<ItemsPage>) {
+    let input = (ALICE, collection, cursor, limit).encode();
+    let (status, output) =
+        call_extension(ALICE, func_id(VERSION, function::GET_OWNED_ITEMS), &input);
+    let page = (status == SUCCESS).then(|| Page::decode_all(&mut &output[..]).expect("a page"));
+    (status, page)
+}
+
+#[test]
+fn get_owned_items_continues_from_the_cursor() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        for collection in [Some(3), None] {
+            let (_, first) = owned_items(collection, None, 1);
+            let first = first.expect("first page");
+            assert_eq!(first.items.len(), 1);
+            let (_, second) = owned_items(collection, first.next, 1);
+            let second = second.expect("second page");
+            assert_eq!(second.items.len(), 1);
+            let mut items = [first.items[0], second.items[0]];
+            items.sort();
+            assert_eq!(items, [(3, 42), (3, 43)]);
+            let (_, last) = owned_items(collection, second.next, 1);
+            assert_eq!(
+                last,
+                Some(Page {
+                    items: vec![],
+                    next: None
+                })
+            );
+        }
+
+        assert_eq!(
+            owned_items(Some(2), Some((3, 42)), 1),
+            (NftsError::InvalidInput as u32, None)
+        );
+    });
+}
+
+#[test]
+fn get_owned_items_refunds_the_items_not_found() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        let weight = |limit: u32| {
+            let input = (ALICE, Some(3u32), None::<(u32, u32)>, limit).encode();
+            extension_weight(ALICE, func_id(VERSION, function::GET_OWNED_ITEMS), &input)
+        };
+        // The weight grows with the items found, not with the limit: both pages hold the 2 items.
+        assert!(weight(1).ref_time() < weight(2).ref_time());
+        assert_eq!(weight(3), weight(MAX_PAGE_SIZE));
+    });
+}
+
+/// All the pages of a collection query, starting without cursor.
+fn collect_pages(function: u8, input: impl Fn(Option<u32>) -> Vec<u8>) -> Vec<u32> {
+    let mut collections = Vec::new();
+    let mut cursor = None;
+    loop {
+        let (status, output) = call_extension(ALICE, func_id(VERSION, function), &input(cursor));
+        assert_eq!(status, SUCCESS);
+        let page = Page::<u32, u32>::decode_all(&mut &output[..]).expect("a page");
+        collections.extend(page.items);
+        cursor = page.next;
+        if cursor.is_none() {
+            return collections;
+        }
+    }
+}
+
+#[test]
+fn get_collections_pages_through_the_collections() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        let mut collections =
+            collect_pages(function::GET_COLLECTIONS, |cursor| (cursor, 3u32).encode());
+        collections.sort();
+        assert_eq!(collections, (0..7).collect::<Vec<_>>());
+
+        let mut owned = collect_pages(function::GET_OWNED_COLLECTIONS, |cursor| {
+            (ALICE, cursor, 3u32).encode()
+        });
+        owned.sort();
+        assert_eq!(owned, (0..7).collect::<Vec<_>>());
+        assert!(collect_pages(function::GET_OWNED_COLLECTIONS, |cursor| {
+            (BOB, cursor, 3u32).encode()
+        })
+        .is_empty());
+    });
+}
+
+#[test]
+fn get_configs_return_the_pallet_configs() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(TRUSTED);
+        let config = CollectionConfigExt::<u128, u32, u32>::fully_locked();
+        let (status, _) = call_extension(
+            TRUSTED,
+            func_id(VERSION, function::FORCE_CREATE),
+            &(ALICE, config).encode(),
+        );
+        assert_eq!(status, SUCCESS);
+        let settings = ItemSettingsExt::soulbound();
+        let (status, _) = call_extension(
+            TRUSTED,
+            func_id(VERSION, function::FORCE_MINT),
+            &(0u32, 42u32, ALICE, settings).encode(),
+        );
+        assert_eq!(status, SUCCESS);
+
+        let get_collection_config = func_id(VERSION, function::GET_COLLECTION_CONFIG);
+        assert_eq!(
+            call_extension(TRUSTED, get_collection_config, &0u32.encode()),
+            (SUCCESS, Some(config).encode())
+        );
+        assert_eq!(
+            call_extension(TRUSTED, get_collection_config, &1u32.encode()),
+            (
+                SUCCESS,
+                None::<CollectionConfigExt<u128, u32, u32>>.encode()
+            )
+        );
+        let get_item_config = func_id(VERSION, function::GET_ITEM_CONFIG);
+        assert_eq!(
+            call_extension(TRUSTED, get_item_config, &(0u32, 42u32).encode()),
+            (SUCCESS, Some(settings).encode())
+        );
+        assert_eq!(
+            call_extension(TRUSTED, get_item_config, &(0u32, 43u32).encode()),
+            (SUCCESS, None::<ItemSettingsExt>.encode())
+        );
+    });
+}
+
+#[test]
+fn get_nfts_constants_returns_the_pallet_constants() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        let (status, output) =
+            call_extension(ALICE, func_id(VERSION, function::GET_NFTS_CONSTANTS), &[]);
+        assert_eq!(status, SUCCESS);
+        let all_enabled = PalletFeaturesExt {
+            trading: true,
+            attributes: true,
+            approvals: true,
+            swaps: true,
+        };
+        assert_eq!(
+            NftsConstantsExt::<u128, u32>::decode_all(&mut &output[..]),
+            Ok(NftsConstantsExt {
+                collection_deposit: 100,
+                item_deposit: 1,
+                metadata_deposit_base: 1,
+                attribute_deposit_base: 0,
+                deposit_per_byte: 0,
+                string_limit: 50,
+                key_limit: 50,
+                value_limit: 50,
+                approvals_limit: 10,
+                item_attributes_approvals_limit: 2,
+                max_tips: 10,
+                max_deadline_duration: 10_000,
+                max_attributes_per_call: 2,
+                features: all_enabled,
+            })
+        );
+    });
+}
+
+#[test]
+fn inputs_shorter_than_their_max_length_are_read() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        // Without a deadline the input is shorter than its max encoded length.
+        let input = (3u32, 43u32, BOB, None::<u32>).encode();
+        assert_eq!(
+            call_extension(ALICE, func_id(VERSION, function::APPROVE_TRANSFER), &input),
+            (SUCCESS, vec![])
+        );
+        assert_eq!(
+            call_extension(
+                ALICE,
+                func_id(VERSION, function::GET_APPROVAL),
+                &(3u32, 43u32, BOB).encode()
+            ),
+            (SUCCESS, Some(None::<u32>).encode())
+        );
+    });
+}
+
+#[test]
+fn byte_inputs_over_the_pallet_limits_are_rejected() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        deploy(TRUSTED);
+        let namespace = AttributeNamespaceExt::<[u8; 32]>::CollectionOwner;
+        let set_attribute = |key: Vec<u8>, value: Vec<u8>| {
+            let input = (None::<[u8; 32]>, 3u32, Some(42u32), namespace, key, value).encode();
+            call_extension(
+                TRUSTED,
+                func_id(VERSION, function::FORCE_SET_ATTRIBUTE),
+                &input,
+            )
+        };
+        assert_eq!(set_attribute(vec![0; 50], vec![0; 50]), (SUCCESS, vec![]));
+        assert_eq!(
+            set_attribute(vec![0; 51], vec![0; 50]),
+            (NftsError::InputTooLarge as u32, vec![])
+        );
+        assert_eq!(
+            set_attribute(vec![0; 50], vec![0; 1_000]),
+            (NftsError::InputTooLarge as u32, vec![])
+        );
+
+        let input = (3u32, Some(42u32), namespace, vec![0u8; 51]).encode();
+        assert_eq!(
+            call_extension(ALICE, func_id(VERSION, function::GET_ATTRIBUTE), &input),
+            (NftsError::InputTooLarge as u32, vec![])
+        );
+    });
+}
+
+#[test]
+fn legacy_function_ids_are_served() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        for version in [LEGACY_VERSION, VERSION] {
+            assert_eq!(
+                call_extension(
+                    ALICE,
+                    func_id(version, function::GET_COLLECTION_DEPOSIT),
+                    &[]
+                ),
+                (SUCCESS, 100u128.encode())
+            );
+        }
+        assert_eq!(
+            call_extension(
+                ALICE,
+                func_id(LEGACY_VERSION, function::GET_NFTS_CONSTANTS),
+                &[]
+            ),
+            (NftsError::UnsupportedFunction as u32, vec![])
+        );
+    });
+}
+
+#[test]
+fn get_capabilities_lists_the_functions_and_features() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        let (status, output) =
+            call_extension(ALICE, func_id(VERSION, function::GET_CAPABILITIES), &[]);
+        assert_eq!(status, SUCCESS);
+        let capabilities = CapabilitiesExt::decode_all(&mut &output[..]).expect("capabilities");
+        assert_eq!(capabilities.version, VERSION);
+        assert_eq!(capabilities.functions.len(), FUNCTIONS.len());
+        assert!(capabilities.supports(func_id(VERSION, function::BATCH_MINT)));
+        assert!(!capabilities.supports(func_id(LEGACY_VERSION, function::BATCH_MINT)));
+        assert!(capabilities.features.trading && capabilities.features.swaps);
+    });
+}
+
+#[test]
+fn unknown_function_ids_return_a_status() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        let unsupported = (NftsError::UnsupportedFunction as u32, vec![]);
+        assert_eq!(
+            call_extension(ALICE, func_id(VERSION, 99), &[]),
+            unsupported
+        );
+        assert_eq!(
+            call_extension(ALICE, func_id(VERSION + 1, function::CREATE), &[]),
+            unsupported
+        );
+        // Only the extrinsics can be dry run.
+        assert_eq!(
+            call_extension(
+                ALICE,
+                dry_run_func_id(VERSION, function::GET_NFTS_CONSTANTS),
+                &[]
+            ),
+            unsupported
+        );
+    });
+}
+
+#[test]
+fn call_filter_denies_the_contracts() {
+    new_test_ext(&[ALICE]).execute_with(|| {
+        deploy(ALICE);
+        deploy(FILTERED);
+        let get_deposit = func_id(VERSION, function::GET_COLLECTION_DEPOSIT);
+        assert_eq!(
+            call_extension(ALICE, get_deposit, &[]),
+            (SUCCESS, 100u128.encode())
+        );
+        assert_eq!(
+            call_extension(FILTERED, get_deposit, &[]),
+            (NftsError::NoPermission as u32, vec![])
+        );
+    });
+}
+
+#[test]
+fn atomic_batches_roll_back_on_a_failure() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        let batch_mint = func_id(VERSION, function::BATCH_MINT);
+        let entries = vec![
+            (50u32, BOB, None::<MintWitnessExt<u32>>),
+            (42u32, BOB, None),
+        ];
+
+        let input = (3u32, entries.clone(), BatchModeExt::Atomic).encode();
+        assert_eq!(
+            call_extension(ALICE, batch_mint, &input),
+            (NftsError::AlreadyExists as u32, vec![])
+        );
+        assert_eq!(Nfts::owner(3, 50), None);
+
+        let input = (3u32, entries, BatchModeExt::BestEffort).encode();
+        let statuses = vec![SUCCESS, NftsError::AlreadyExists as u32];
+        assert_eq!(
+            call_extension(ALICE, batch_mint, &input),
+            (SUCCESS, Ok::<_, NftsError>(statuses).encode())
+        );
+        assert_eq!(Nfts::owner(3, 50), Some(BOB.into()));
+    });
+}
+
+#[test]
+fn dry_run_leaves_the_storage_unchanged() {
+    new_test_ext(&[ALICE, BOB, CHARLIE]).execute_with(|| {
+        setup();
+        let input = (3u32, 42u32, BOB).encode();
+        let (status, output) =
+            call_extension(ALICE, dry_run_func_id(VERSION, function::TRANSFER), &input);
+        assert_eq!(status, SUCCESS);
+        let result = DryRunResultExt::decode_all(&mut &output[..]).expect("a dry run result");
+        assert_eq!(result.status, SUCCESS);
+        assert!(result.weight.ref_time > 0);
+        assert_eq!(Nfts::owner(3, 42), Some(ALICE.into()));
+
+        assert_eq!(
+            call_extension(ALICE, func_id(VERSION, function::TRANSFER), &input),
+            (SUCCESS, vec![])
+        );
+        assert_eq!(Nfts::owner(3, 42), Some(BOB.into()));
+    });
+}
@@ -0,0 +1,335 @@
+//! Weights of the NFTs chain extension's own overhead.
+//!
+//! They cover what the extension does around the nfts pallet: reading and decoding the input
+//! buffer, converting between extension and pallet types, building pages and writing the output
+//! buffer. The weights of the dispatched pallet calls are charged on top of them.
+//!
+//! Generated with the `benchmarking` module of this crate on its test runtime (`impls_runtime`
+//! mock), in a native release build with 50 steps and 20 repeats, over an in-memory state that
+//! tracks the storage reads. The proof sizes are estimated from the max encoded lengths of the
+//! storage entries read. A runtime should generate its own weights with its node and use them
+//! instead:
+//!
+//! ```text
+//! ./target/release/node benchmark pallet \
+//!     --chain=dev \
+//!     --steps=50 \
+//!     --repeat=20 \
+//!     --pallet=nfts_extension \
+//!     --extrinsic=* \
+//!     --execution=wasm \
+//!     --wasm-execution=compiled \
+//!     --output=./weights/nfts_extension.rs
+//! ```
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for the NFTs chain extension.
+pub trait WeightInfo {
+    fn read_input(n: u32) -> Weight;
+    fn write_output(n: u32) -> Weight;
+    fn create() -> Weight;
+    fn redeposit() -> Weight;
+    fn batch_mint(n: u32) -> Weight;
+    fn batch_transfer(n: u32) -> Weight;
+    fn transfer() -> Weight;
+    fn approve_transfer() -> Weight;
+    fn cancel_approval() -> Weight;
+    fn force_create() -> Weight;
+    fn force_mint() -> Weight;
+    fn force_collection_owner() -> Weight;
+    fn force_collection_config() -> Weight;
+    fn force_set_attribute(k: u32, v: u32) -> Weight;
+    fn get_collection() -> Weight;
+    fn get_owned_items(n: u32) -> Weight;
+    fn get_collections(n: u32) -> Weight;
+    fn get_owned_collections(n: u32) -> Weight;
+    fn get_collection_config() -> Weight;
+    fn get_item_config() -> Weight;
+    fn get_item_owner() -> Weight;
+    fn get_balance(n: u32) -> Weight;
+    fn get_approval() -> Weight;
+    fn get_attribute(k: u32) -> Weight;
+    fn get_capabilities() -> Weight;
+    fn get_constant() -> Weight;
+    fn get_nfts_constants() -> Weight;
+}
+
+/// Benchmarked weights, with the storage reads priced by `T::DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// The range of component `n` is `[0, 16384]`.
+    fn read_input(n: u32) -> Weight {
+        Weight::from_parts(614_661, 0)
+            .saturating_add(Weight::from_parts(45, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `n` is `[0, 16384]`.
+    fn write_output(n: u32) -> Weight {
+        Weight::from_parts(83_036, 0)
+            .saturating_add(Weight::from_parts(25, 0).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts NextCollectionId (r:1 w:0)
+    /// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn create() -> Weight {
+        Weight::from_parts(998_000, 499).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    fn redeposit() -> Weight {
+        Weight::from_parts(196_000, 0)
+    }
+    /// The range of component `n` is `[0, 100]`.
+    fn batch_mint(n: u32) -> Weight {
+        Weight::from_parts(1_190_983, 0)
+            .saturating_add(Weight::from_parts(69_573, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `n` is `[0, 100]`.
+    fn batch_transfer(n: u32) -> Weight {
+        Weight::from_parts(1_177_094, 0)
+            .saturating_add(Weight::from_parts(68_433, 0).saturating_mul(n.into()))
+    }
+    fn transfer() -> Weight {
+        Weight::from_parts(139_000, 0)
+    }
+    fn approve_transfer() -> Weight {
+        Weight::from_parts(137_000, 0)
+    }
+    fn cancel_approval() -> Weight {
+        Weight::from_parts(123_000, 0)
+    }
+    /// Storage: Nfts NextCollectionId (r:1 w:0)
+    /// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn force_create() -> Weight {
+        Weight::from_parts(818_000, 499).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    fn force_mint() -> Weight {
+        Weight::from_parts(138_000, 0)
+    }
+    fn force_collection_owner() -> Weight {
+        Weight::from_parts(134_000, 0)
+    }
+    fn force_collection_config() -> Weight {
+        Weight::from_parts(145_000, 0)
+    }
+    /// The range of component `k` is `[0, 50]`.
+    /// The range of component `v` is `[0, 50]`.
+    fn force_set_attribute(_k: u32, v: u32) -> Weight {
+        Weight::from_parts(480_270, 0)
+            .saturating_add(Weight::from_parts(649, 0).saturating_mul(v.into()))
+    }
+    /// Storage: Nfts Collection (r:1 w:0)
+    /// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    fn get_collection() -> Weight {
+        Weight::from_parts(2_484_000, 2_559).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Account (r:100 w:0)
+    /// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_owned_items(n: u32) -> Weight {
+        Weight::from_parts(1_070_264, 0)
+            .saturating_add(Weight::from_parts(1_599_611, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_563).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts Collection (r:100 w:0)
+    /// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_collections(n: u32) -> Weight {
+        Weight::from_parts(622_281, 0)
+            .saturating_add(Weight::from_parts(1_490_289, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_559).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts CollectionAccount (r:100 w:0)
+    /// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_owned_collections(n: u32) -> Weight {
+        Weight::from_parts(4_490_876, 0)
+            .saturating_add(Weight::from_parts(1_377_428, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_543).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts CollectionConfigOf (r:1 w:0)
+    /// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+    fn get_collection_config() -> Weight {
+        Weight::from_parts(1_553_000, 2_548).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts ItemConfigOf (r:1 w:0)
+    /// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    fn get_item_config() -> Weight {
+        Weight::from_parts(2_003_000, 2_523).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Item (r:1 w:0)
+    /// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
+    fn get_item_owner() -> Weight {
+        Weight::from_parts(1_860_000, 2_966).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Account (r:100 w:0)
+    /// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_balance(n: u32) -> Weight {
+        Weight::from_parts(1_133_000, 0)
+            .saturating_add(Weight::from_parts(1_212_971, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_563).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts Item (r:1 w:0)
+    /// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
+    fn get_approval() -> Weight {
+        Weight::from_parts(3_509_000, 2_966).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Attribute (r:1 w:0)
+    /// Proof: Nfts Attribute (max_values: None, max_size: Some(257), added: 2732, mode: MaxEncodedLen)
+    /// The range of component `k` is `[0, 50]`.
+    fn get_attribute(_k: u32) -> Weight {
+        Weight::from_parts(4_024_782, 2_732).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    fn get_capabilities() -> Weight {
+        Weight::from_parts(151_000, 0)
+    }
+    fn get_constant() -> Weight {
+        Weight::from_parts(98_000, 0)
+    }
+    fn get_nfts_constants() -> Weight {
+        Weight::from_parts(116_000, 0)
+    }
+}
+
+/// Benchmarked weights, with the storage reads priced by `RocksDbWeight`. For tests.
+impl WeightInfo for () {
+    /// The range of component `n` is `[0, 16384]`.
+    fn read_input(n: u32) -> Weight {
+        Weight::from_parts(614_661, 0)
+            .saturating_add(Weight::from_parts(45, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `n` is `[0, 16384]`.
+    fn write_output(n: u32) -> Weight {
+        Weight::from_parts(83_036, 0)
+            .saturating_add(Weight::from_parts(25, 0).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts NextCollectionId (r:1 w:0)
+    /// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn create() -> Weight {
+        Weight::from_parts(998_000, 499).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    fn redeposit() -> Weight {
+        Weight::from_parts(196_000, 0)
+    }
+    /// The range of component `n` is `[0, 100]`.
+    fn batch_mint(n: u32) -> Weight {
+        Weight::from_parts(1_190_983, 0)
+            .saturating_add(Weight::from_parts(69_573, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `n` is `[0, 100]`.
+    fn batch_transfer(n: u32) -> Weight {
+        Weight::from_parts(1_177_094, 0)
+            .saturating_add(Weight::from_parts(68_433, 0).saturating_mul(n.into()))
+    }
+    fn transfer() -> Weight {
+        Weight::from_parts(139_000, 0)
+    }
+    fn approve_transfer() -> Weight {
+        Weight::from_parts(137_000, 0)
+    }
+    fn cancel_approval() -> Weight {
+        Weight::from_parts(123_000, 0)
+    }
+    /// Storage: Nfts NextCollectionId (r:1 w:0)
+    /// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    fn force_create() -> Weight {
+        Weight::from_parts(818_000, 499).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    fn force_mint() -> Weight {
+        Weight::from_parts(138_000, 0)
+    }
+    fn force_collection_owner() -> Weight {
+        Weight::from_parts(134_000, 0)
+    }
+    fn force_collection_config() -> Weight {
+        Weight::from_parts(145_000, 0)
+    }
+    /// The range of component `k` is `[0, 50]`.
+    /// The range of component `v` is `[0, 50]`.
+    fn force_set_attribute(_k: u32, v: u32) -> Weight {
+        Weight::from_parts(480_270, 0)
+            .saturating_add(Weight::from_parts(649, 0).saturating_mul(v.into()))
+    }
+    /// Storage: Nfts Collection (r:1 w:0)
+    /// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    fn get_collection() -> Weight {
+        Weight::from_parts(2_484_000, 2_559).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Account (r:100 w:0)
+    /// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_owned_items(n: u32) -> Weight {
+        Weight::from_parts(1_070_264, 0)
+            .saturating_add(Weight::from_parts(1_599_611, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_563).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts Collection (r:100 w:0)
+    /// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_collections(n: u32) -> Weight {
+        Weight::from_parts(622_281, 0)
+            .saturating_add(Weight::from_parts(1_490_289, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_559).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts CollectionAccount (r:100 w:0)
+    /// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_owned_collections(n: u32) -> Weight {
+        Weight::from_parts(4_490_876, 0)
+            .saturating_add(Weight::from_parts(1_377_428, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_543).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts CollectionConfigOf (r:1 w:0)
+    /// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+    fn get_collection_config() -> Weight {
+        Weight::from_parts(1_553_000, 2_548).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts ItemConfigOf (r:1 w:0)
+    /// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+    fn get_item_config() -> Weight {
+        Weight::from_parts(2_003_000, 2_523).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Item (r:1 w:0)
+    /// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
+    fn get_item_owner() -> Weight {
+        Weight::from_parts(1_860_000, 2_966).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Account (r:100 w:0)
+    /// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+    /// The range of component `n` is `[0, 100]`.
+    fn get_balance(n: u32) -> Weight {
+        Weight::from_parts(1_133_000, 0)
+            .saturating_add(Weight::from_parts(1_212_971, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2_563).saturating_mul(n.into()))
+    }
+    /// Storage: Nfts Item (r:1 w:0)
+    /// Proof: Nfts Item (max_values: None, max_size: Some(491), added: 2966, mode: MaxEncodedLen)
+    fn get_approval() -> Weight {
+        Weight::from_parts(3_509_000, 2_966).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Storage: Nfts Attribute (r:1 w:0)
+    /// Proof: Nfts Attribute (max_values: None, max_size: Some(257), added: 2732, mode: MaxEncodedLen)
+    /// The range of component `k` is `[0, 50]`.
+    fn get_attribute(_k: u32) -> Weight {
+        Weight::from_parts(4_024_782, 2_732).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    fn get_capabilities() -> Weight {
+        Weight::from_parts(151_000, 0)
+    }
+    fn get_constant() -> Weight {
+        Weight::from_parts(98_000, 0)
+    }
+    fn get_nfts_constants() -> Weight {
+        Weight::from_parts(116_000, 0)
+    }
+}
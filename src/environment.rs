@@ -0,0 +1,71 @@
+//! Id types of the nfts pallet, configured alongside the ink environment of the contracts.
+//!
+//! The ids are SCALE encoded as they are, so they must be the `CollectionId` and `ItemId` the
+//! runtime configures for `pallet_nfts`: a contract built with other types traps when decoding
+//! the output of the extension, or gets its input rejected. [`DefaultEnvironment`] has the `u32`
+//! ids of the usual runtimes. For a runtime with other ids, implement [`NftsEnvironment`] for the
+//! environment of the contract and call the extension through [`NftsExtensionFor`]:
+//!
+//! ```
+//! use ink::env::{DefaultEnvironment, Environment};
+//! use nfts_extension::{NftsEnvironment, NftsExtensionFor};
+//!
+//! #[derive(Debug, Clone, PartialEq, Eq)]
+//! pub enum CustomEnvironment {}
+//!
+//! impl Environment for CustomEnvironment {
+//!     const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+//!     type AccountId = <DefaultEnvironment as Environment>::AccountId;
+//!     type Balance = <DefaultEnvironment as Environment>::Balance;
+//!     type Hash = <DefaultEnvironment as Environment>::Hash;
+//!     type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+//!     type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+//!     type ChainExtension = <DefaultEnvironment as Environment>::ChainExtension;
+//! }
+//!
+//! impl NftsEnvironment for CustomEnvironment {
+//!     type CollectionId = u64;
+//!     type ItemId = u128;
+//! }
+//!
+//! type Nfts = NftsExtensionFor<CustomEnvironment>;
+//! ```
+//!
+//! [`NftsExtensionFor`]: crate::NftsExtensionFor
+
+use core::fmt::Debug;
+use ink::env::{DefaultEnvironment, Environment};
+use scale::{Codec, MaxEncodedLen};
+use sealed::Sealed;
+
+/// Environment of the contracts calling the extension, with the id types of the nfts pallet.
+pub trait NftsEnvironment: Environment {
+    /// `pallet_nfts::Config::CollectionId` of the runtime.
+    type CollectionId: NftsId;
+    /// `pallet_nfts::Config::ItemId` of the runtime.
+    type ItemId: NftsId;
+}
+
+impl NftsEnvironment for DefaultEnvironment {
+    type CollectionId = u32;
+    type ItemId = u32;
+}
+
+/// Types the ids of the nfts pallet can be configured to: `u32`, `u64` or `u128`.
+pub trait NftsId:
+    Copy + Clone + PartialEq + Eq + PartialOrd + Ord + Debug + Codec + MaxEncodedLen + Sealed
+{
+}
+
+impl NftsId for u32 {}
+impl NftsId for u64 {}
+impl NftsId for u128 {}
+
+mod sealed {
+    /// Keeps the id types to the ones the runtime can configure.
+    pub trait Sealed {}
+
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for u128 {}
+}
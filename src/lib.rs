@@ -1,70 +1,590 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod environment;
 pub mod errors;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
+pub mod psp34;
+#[cfg(test)]
+mod tests;
 pub mod types;
 
-pub use nfts_extension_types::{CollectionConfigExt, Origin};
+pub use environment::{NftsEnvironment, NftsId};
+pub use nfts_extension_types::{flags, ids};
+pub use nfts_extension_types::{
+    AttributeNamespaceExt, BatchModeExt, CapabilitiesExt, CollectionConfigExt, DryRunResultExt,
+    ItemSettingsExt, MintWitnessExt, Origin, Page, WeightExt, MAX_BATCH_SIZE, MAX_PAGE_SIZE,
+    MAX_REDEPOSIT_ITEMS,
+};
 
 use crate::errors::NftsError;
-use crate::types::{DefaultCollectionDetailsExt, DefaultCreateInput};
+use crate::types::{
+    BatchMintEntryFor, CollectionConfigExtFor, CollectionDetailsExtFor, CollectionsPageFor,
+    CreateInputFor, DryRun, NftsConstantsExtFor, OwnedItemsPageFor,
+};
+use core::marker::PhantomData;
+use ink::env::chain_extension::ChainExtensionMethod;
 use ink::env::{DefaultEnvironment, Environment};
+use ink::prelude::vec::Vec;
+use nfts_extension_types::ids::{dry_run_func_id, func_id, function, VERSION};
 use scale::{Decode, Encode};
 
 pub type AccountId = <DefaultEnvironment as Environment>::AccountId;
 pub type Balance = <DefaultEnvironment as Environment>::Balance;
 pub type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
-pub type CollectionId = u32;
+pub type CollectionId = <DefaultEnvironment as NftsEnvironment>::CollectionId;
+pub type ItemId = <DefaultEnvironment as NftsEnvironment>::ItemId;
 
-pub struct NftsExtension;
+/// Start building the call of an extension function, in the interface version of this release.
+///
+/// The functions returning a value along their status, such as `create`, get the whole `Result`
+/// written by the runtime: ink decodes the output of a result method as the `Ok` value alone, so
+/// they declare it as a plain value and flatten it.
+fn method(function: u8) -> ChainExtensionMethod<(), (), (), false> {
+    ChainExtensionMethod::build(func_id(VERSION, function))
+}
+
+/// Functions of the extension, for the contracts of the environment `E`. Its ids must be the ones
+/// of the runtime, see the `environment` module.
+pub struct NftsExtensionFor<E>(PhantomData<E>);
+
+/// Functions of the extension, for the contracts of the default environment.
+pub type NftsExtension = NftsExtensionFor<DefaultEnvironment>;
+
+impl<E: NftsEnvironment> NftsExtensionFor<E> {
+    // The getters of the legacy functions, the four constants and `get_collection`, return the
+    // value alone. The others return the status code of the runtime as an error, such as
+    // `UnsupportedFunction` when the runtime doesn't serve them or `NoPermission` when its call
+    // filter denies them.
 
-impl NftsExtension {
     // Getters constants
     pub fn get_approvals_limit() -> u32 {
-        ::ink::env::chain_extension::ChainExtensionMethod::build(0100u32)
+        method(function::GET_APPROVALS_LIMIT)
             .input::<()>()
             .output::<u32, false>()
             .ignore_error_code()
             .call(&())
     }
-    pub fn get_attribute_deposit_base() -> Balance {
-        ::ink::env::chain_extension::ChainExtensionMethod::build(0101u32)
+    pub fn get_attribute_deposit_base() -> E::Balance {
+        method(function::GET_ATTRIBUTE_DEPOSIT_BASE)
             .input::<()>()
-            .output::<Balance, false>()
+            .output::<E::Balance, false>()
             .ignore_error_code()
             .call(&())
     }
-    pub fn get_collection_deposit() -> Balance {
-        ::ink::env::chain_extension::ChainExtensionMethod::build(0102u32)
+    pub fn get_collection_deposit() -> E::Balance {
+        method(function::GET_COLLECTION_DEPOSIT)
             .input::<()>()
-            .output::<Balance, false>()
+            .output::<E::Balance, false>()
             .ignore_error_code()
             .call(&())
     }
-    pub fn get_deposit_per_byte() -> Balance {
-        ::ink::env::chain_extension::ChainExtensionMethod::build(0103u32)
+    pub fn get_deposit_per_byte() -> E::Balance {
+        method(function::GET_DEPOSIT_PER_BYTE)
             .input::<()>()
-            .output::<Balance, false>()
+            .output::<E::Balance, false>()
             .ignore_error_code()
             .call(&())
     }
+    /// Query all the constants of the pallet-nfts in a single call
+    pub fn get_nfts_constants() -> Result<NftsConstantsExtFor<E>, NftsError> {
+        method(function::GET_NFTS_CONSTANTS)
+            .input::<()>()
+            .output::<NftsConstantsExtFor<E>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&())
+    }
+
+    /// Query the version of the extension, the functions it serves and the pallet features enabled
+    /// in the runtime. Check a function is supported before calling it, to degrade gracefully on
+    /// the networks where it isn't available.
+    pub fn get_capabilities() -> Result<CapabilitiesExt, NftsError> {
+        method(function::GET_CAPABILITIES)
+            .input::<()>()
+            .output::<CapabilitiesExt, false>()
+            .handle_error_code::<NftsError>()
+            .call(&())
+    }
 
     // Getters chain state
     /// Query the collection details of a specified ID
-    /// TODO: make the input id as an Option and if None, query all the collections ?
-    pub fn get_collection(id: CollectionId) -> Option<DefaultCollectionDetailsExt> {
-        ::ink::env::chain_extension::ChainExtensionMethod::build(0002u32)
-            .input::<CollectionId>()
-            .output::<Option<DefaultCollectionDetailsExt>, false>()
+    pub fn get_collection(id: E::CollectionId) -> Option<CollectionDetailsExtFor<E>> {
+        method(function::GET_COLLECTION)
+            .input::<E::CollectionId>()
+            .output::<Option<CollectionDetailsExtFor<E>>, false>()
             .ignore_error_code()
             .call(&id)
     }
 
-    /// Calls create() in the pallet-assets
-    pub fn create(input: DefaultCreateInput) -> Result<(), NftsError> {
-        ::ink::env::chain_extension::ChainExtensionMethod::build(0001u32)
-            .input::<DefaultCreateInput>()
-            .output::<Result<(), NftsError>, true>()
+    /// Query a page of the items owned by `account`, optionally only in the given collection.
+    /// Pass the `next` cursor of the returned page to get the following one, at most `limit`
+    /// (capped to `MAX_PAGE_SIZE`) items are returned per call. With a `collection`, the cursor
+    /// must be in it or `InvalidInput` is returned.
+    ///
+    /// The items come in the order of the `Account` storage of the pallet, whose keys are hashed
+    /// with `Blake2_128Concat`: the order of the hashes, not of the ids. It's stable while the
+    /// items are held, so the pages don't repeat or skip any item that isn't moved in between.
+    pub fn get_owned_items(
+        account: E::AccountId,
+        collection: Option<E::CollectionId>,
+        cursor: Option<(E::CollectionId, E::ItemId)>,
+        limit: u32,
+    ) -> Result<OwnedItemsPageFor<E>, NftsError> {
+        method(function::GET_OWNED_ITEMS)
+            .input::<(
+                E::AccountId,
+                Option<E::CollectionId>,
+                Option<(E::CollectionId, E::ItemId)>,
+                u32,
+            )>()
+            .output::<OwnedItemsPageFor<E>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(account, collection, cursor, limit))
+    }
+
+    /// Query a page of all the existing collections.
+    /// Pass the `next` cursor of the returned page to get the following one, at most `limit`
+    /// (capped to `MAX_PAGE_SIZE`) collections are returned per call.
+    pub fn get_collections(
+        cursor: Option<E::CollectionId>,
+        limit: u32,
+    ) -> Result<CollectionsPageFor<E>, NftsError> {
+        method(function::GET_COLLECTIONS)
+            .input::<(Option<E::CollectionId>, u32)>()
+            .output::<CollectionsPageFor<E>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(cursor, limit))
+    }
+
+    /// Query a page of the collections owned by `owner`.
+    /// Pass the `next` cursor of the returned page to get the following one, at most `limit`
+    /// (capped to `MAX_PAGE_SIZE`) collections are returned per call.
+    pub fn get_owned_collections(
+        owner: E::AccountId,
+        cursor: Option<E::CollectionId>,
+        limit: u32,
+    ) -> Result<CollectionsPageFor<E>, NftsError> {
+        method(function::GET_OWNED_COLLECTIONS)
+            .input::<(E::AccountId, Option<E::CollectionId>, u32)>()
+            .output::<CollectionsPageFor<E>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(owner, cursor, limit))
+    }
+
+    /// Query the config of a collection, `None` if the collection doesn't exist
+    pub fn get_collection_config(
+        collection: E::CollectionId,
+    ) -> Result<Option<CollectionConfigExtFor<E>>, NftsError> {
+        method(function::GET_COLLECTION_CONFIG)
+            .input::<E::CollectionId>()
+            .output::<Option<CollectionConfigExtFor<E>>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&collection)
+    }
+
+    /// Query the settings of an item, `None` if the item has no config
+    pub fn get_item_config(
+        collection: E::CollectionId,
+        item: E::ItemId,
+    ) -> Result<Option<ItemSettingsExt>, NftsError> {
+        method(function::GET_ITEM_CONFIG)
+            .input::<(E::CollectionId, E::ItemId)>()
+            .output::<Option<ItemSettingsExt>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item))
+    }
+
+    /// Query the owner of an item, `None` if the item doesn't exist
+    pub fn get_item_owner(
+        collection: E::CollectionId,
+        item: E::ItemId,
+    ) -> Result<Option<E::AccountId>, NftsError> {
+        method(function::GET_ITEM_OWNER)
+            .input::<(E::CollectionId, E::ItemId)>()
+            .output::<Option<E::AccountId>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item))
+    }
+
+    /// Query the number of items of a collection owned by `owner`
+    pub fn get_balance(collection: E::CollectionId, owner: E::AccountId) -> Result<u32, NftsError> {
+        method(function::GET_BALANCE)
+            .input::<(E::CollectionId, E::AccountId)>()
+            .output::<u32, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, owner))
+    }
+
+    /// Query the approval of `delegate` to transfer an item: `None` if it isn't approved,
+    /// otherwise the block number after which the approval expires, if any. An expired approval
+    /// is still returned until it's cancelled.
+    pub fn get_approval(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        delegate: E::AccountId,
+    ) -> Result<Option<Option<E::BlockNumber>>, NftsError> {
+        method(function::GET_APPROVAL)
+            .input::<(E::CollectionId, E::ItemId, E::AccountId)>()
+            .output::<Option<Option<E::BlockNumber>>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item, delegate))
+    }
+
+    /// Query the value of an attribute of a collection, or of one of its items, `None` if it
+    /// isn't set. A `key` longer than the pallet `KeyLimit` is rejected with `InputTooLarge`.
+    pub fn get_attribute(
+        collection: E::CollectionId,
+        maybe_item: Option<E::ItemId>,
+        namespace: AttributeNamespaceExt<E::AccountId>,
+        key: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, NftsError> {
+        method(function::GET_ATTRIBUTE)
+            .input::<(
+                E::CollectionId,
+                Option<E::ItemId>,
+                AttributeNamespaceExt<E::AccountId>,
+                Vec<u8>,
+            )>()
+            .output::<Result<Option<Vec<u8>>, NftsError>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, maybe_item, namespace, key))
+            .and_then(|result| result)
+    }
+
+    /// Calls create() in the pallet-nfts and returns the id of the created collection. The
+    /// collection gets the default config of the pallet, the config of `input` isn't applied, see
+    /// [`Self::force_create`].
+    pub fn create(input: CreateInputFor<E>) -> Result<E::CollectionId, NftsError> {
+        method(function::CREATE)
+            .input::<CreateInputFor<E>>()
+            .output::<Result<E::CollectionId, NftsError>, false>()
             .handle_error_code::<NftsError>()
             .call(&input)
+            .and_then(|result| result)
+    }
+
+    /// Calls redeposit() in the pallet-nfts to re-evaluate the deposits of the given items.
+    /// At most `MAX_REDEPOSIT_ITEMS` items, or `InputTooLarge` is returned.
+    pub fn redeposit(collection: E::CollectionId, items: Vec<E::ItemId>) -> Result<(), NftsError> {
+        method(function::REDEPOSIT)
+            .input::<(E::CollectionId, Vec<E::ItemId>)>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, items))
+    }
+
+    /// Mint several items of a collection in a single call, each entry being the item, its owner
+    /// and the mint witness. At most `MAX_BATCH_SIZE` entries, or `InputTooLarge` is returned.
+    ///
+    /// In `Atomic` mode the error of the first failed mint is returned and none of the items is
+    /// minted. In `BestEffort` mode the result of each mint is returned.
+    ///
+    /// The index of the failed entry isn't returned, the error being a bare status code. Dry run
+    /// the batch in `BestEffort` mode with [`Self::dry_run_batch_mint`] to find it.
+    pub fn batch_mint(
+        collection: E::CollectionId,
+        items: Vec<BatchMintEntryFor<E>>,
+        mode: BatchModeExt,
+    ) -> Result<Vec<Result<(), NftsError>>, NftsError> {
+        let statuses = method(function::BATCH_MINT)
+            .input::<(E::CollectionId, Vec<BatchMintEntryFor<E>>, BatchModeExt)>()
+            .output::<Result<Vec<u32>, NftsError>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, items, mode))??;
+        Ok(into_results(statuses))
+    }
+
+    /// Transfer several items in a single call, each entry being the collection, the item and its
+    /// new owner. At most `MAX_BATCH_SIZE` entries, or `InputTooLarge` is returned.
+    ///
+    /// In `Atomic` mode the error of the first failed transfer is returned and none of the items is
+    /// transferred. In `BestEffort` mode the result of each transfer is returned.
+    ///
+    /// The index of the failed entry isn't returned, the error being a bare status code. Dry run
+    /// the batch in `BestEffort` mode with [`Self::dry_run_batch_transfer`] to find it.
+    pub fn batch_transfer(
+        items: Vec<(E::CollectionId, E::ItemId, E::AccountId)>,
+        mode: BatchModeExt,
+    ) -> Result<Vec<Result<(), NftsError>>, NftsError> {
+        let statuses = method(function::BATCH_TRANSFER)
+            .input::<(
+                Vec<(E::CollectionId, E::ItemId, E::AccountId)>,
+                BatchModeExt,
+            )>()
+            .output::<Result<Vec<u32>, NftsError>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(items, mode))??;
+        Ok(into_results(statuses))
+    }
+
+    /// Calls transfer() in the pallet-nfts. The contract must own the item or be approved to
+    /// transfer it.
+    pub fn transfer(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        dest: E::AccountId,
+    ) -> Result<(), NftsError> {
+        method(function::TRANSFER)
+            .input::<(E::CollectionId, E::ItemId, E::AccountId)>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item, dest))
+    }
+
+    /// Calls approve_transfer() in the pallet-nfts to let `delegate` transfer an item owned by
+    /// the contract, until the given number of blocks has passed if any.
+    pub fn approve_transfer(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        delegate: E::AccountId,
+        maybe_deadline: Option<E::BlockNumber>,
+    ) -> Result<(), NftsError> {
+        method(function::APPROVE_TRANSFER)
+            .input::<(
+                E::CollectionId,
+                E::ItemId,
+                E::AccountId,
+                Option<E::BlockNumber>,
+            )>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item, delegate, maybe_deadline))
+    }
+
+    /// Calls cancel_approval() in the pallet-nfts to revoke the approval of `delegate`
+    pub fn cancel_approval(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        delegate: E::AccountId,
+    ) -> Result<(), NftsError> {
+        method(function::CANCEL_APPROVAL)
+            .input::<(E::CollectionId, E::ItemId, E::AccountId)>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item, delegate))
+    }
+
+    // Privileged extrinsics, only available to contracts trusted by the runtime.
+    // Any other contract gets `NftsError::NoPermission`.
+
+    /// Calls force_create() in the pallet-nfts and returns the id of the created collection
+    pub fn force_create(
+        owner: E::AccountId,
+        config: CollectionConfigExtFor<E>,
+    ) -> Result<E::CollectionId, NftsError> {
+        method(function::FORCE_CREATE)
+            .input::<(E::AccountId, CollectionConfigExtFor<E>)>()
+            .output::<Result<E::CollectionId, NftsError>, false>()
+            .handle_error_code::<NftsError>()
+            .call(&(owner, config))
+            .and_then(|result| result)
+    }
+
+    /// Calls force_mint() in the pallet-nfts
+    pub fn force_mint(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        mint_to: E::AccountId,
+        item_settings: ItemSettingsExt,
+    ) -> Result<(), NftsError> {
+        method(function::FORCE_MINT)
+            .input::<(E::CollectionId, E::ItemId, E::AccountId, ItemSettingsExt)>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, item, mint_to, item_settings))
     }
+
+    /// Calls force_collection_owner() in the pallet-nfts
+    pub fn force_collection_owner(
+        collection: E::CollectionId,
+        owner: E::AccountId,
+    ) -> Result<(), NftsError> {
+        method(function::FORCE_COLLECTION_OWNER)
+            .input::<(E::CollectionId, E::AccountId)>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, owner))
+    }
+
+    /// Calls force_collection_config() in the pallet-nfts
+    pub fn force_collection_config(
+        collection: E::CollectionId,
+        config: CollectionConfigExtFor<E>,
+    ) -> Result<(), NftsError> {
+        method(function::FORCE_COLLECTION_CONFIG)
+            .input::<(E::CollectionId, CollectionConfigExtFor<E>)>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(collection, config))
+    }
+
+    /// Calls force_set_attribute() in the pallet-nfts
+    ///
+    /// A `key` or `value` longer than the pallet `KeyLimit` or `ValueLimit` is rejected with
+    /// `InputTooLarge`.
+    pub fn force_set_attribute(
+        set_as: Option<E::AccountId>,
+        collection: E::CollectionId,
+        maybe_item: Option<E::ItemId>,
+        namespace: AttributeNamespaceExt<E::AccountId>,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), NftsError> {
+        method(function::FORCE_SET_ATTRIBUTE)
+            .input::<(
+                Option<E::AccountId>,
+                E::CollectionId,
+                Option<E::ItemId>,
+                AttributeNamespaceExt<E::AccountId>,
+                Vec<u8>,
+                Vec<u8>,
+            )>()
+            .output::<Result<(), NftsError>, true>()
+            .handle_error_code::<NftsError>()
+            .call(&(set_as, collection, maybe_item, namespace, key, value))
+    }
+
+    // Dry runs of the extrinsics: whether the call would succeed and the weight it would consume,
+    // without keeping any of its changes. The weight is charged as if the call had run.
+
+    /// The id of the collection is returned as by `create`.
+    pub fn dry_run_create(input: CreateInputFor<E>) -> DryRun<E::CollectionId> {
+        dry_run::<_, Result<E::CollectionId, NftsError>>(function::CREATE, &input)
+            .and_then(|result| result)
+    }
+
+    pub fn dry_run_redeposit(collection: E::CollectionId, items: Vec<E::ItemId>) -> DryRun {
+        dry_run(function::REDEPOSIT, &(collection, items))
+    }
+
+    /// The statuses of the entries are returned as by `batch_mint`.
+    pub fn dry_run_batch_mint(
+        collection: E::CollectionId,
+        items: Vec<BatchMintEntryFor<E>>,
+        mode: BatchModeExt,
+    ) -> DryRun<Vec<Result<(), NftsError>>> {
+        dry_run::<_, Result<Vec<u32>, NftsError>>(function::BATCH_MINT, &(collection, items, mode))
+            .and_then(|result| result.map(into_results))
+    }
+
+    /// The statuses of the entries are returned as by `batch_transfer`.
+    pub fn dry_run_batch_transfer(
+        items: Vec<(E::CollectionId, E::ItemId, E::AccountId)>,
+        mode: BatchModeExt,
+    ) -> DryRun<Vec<Result<(), NftsError>>> {
+        dry_run::<_, Result<Vec<u32>, NftsError>>(function::BATCH_TRANSFER, &(items, mode))
+            .and_then(|result| result.map(into_results))
+    }
+
+    pub fn dry_run_transfer(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        dest: E::AccountId,
+    ) -> DryRun {
+        dry_run(function::TRANSFER, &(collection, item, dest))
+    }
+
+    pub fn dry_run_approve_transfer(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        delegate: E::AccountId,
+        maybe_deadline: Option<E::BlockNumber>,
+    ) -> DryRun {
+        dry_run(
+            function::APPROVE_TRANSFER,
+            &(collection, item, delegate, maybe_deadline),
+        )
+    }
+
+    pub fn dry_run_cancel_approval(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        delegate: E::AccountId,
+    ) -> DryRun {
+        dry_run(function::CANCEL_APPROVAL, &(collection, item, delegate))
+    }
+
+    /// The id of the collection is returned as by `force_create`.
+    pub fn dry_run_force_create(
+        owner: E::AccountId,
+        config: CollectionConfigExtFor<E>,
+    ) -> DryRun<E::CollectionId> {
+        dry_run::<_, Result<E::CollectionId, NftsError>>(function::FORCE_CREATE, &(owner, config))
+            .and_then(|result| result)
+    }
+
+    pub fn dry_run_force_mint(
+        collection: E::CollectionId,
+        item: E::ItemId,
+        mint_to: E::AccountId,
+        item_settings: ItemSettingsExt,
+    ) -> DryRun {
+        dry_run(
+            function::FORCE_MINT,
+            &(collection, item, mint_to, item_settings),
+        )
+    }
+
+    pub fn dry_run_force_collection_owner(
+        collection: E::CollectionId,
+        owner: E::AccountId,
+    ) -> DryRun {
+        dry_run(function::FORCE_COLLECTION_OWNER, &(collection, owner))
+    }
+
+    pub fn dry_run_force_collection_config(
+        collection: E::CollectionId,
+        config: CollectionConfigExtFor<E>,
+    ) -> DryRun {
+        dry_run(function::FORCE_COLLECTION_CONFIG, &(collection, config))
+    }
+
+    pub fn dry_run_force_set_attribute(
+        set_as: Option<E::AccountId>,
+        collection: E::CollectionId,
+        maybe_item: Option<E::ItemId>,
+        namespace: AttributeNamespaceExt<E::AccountId>,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> DryRun {
+        dry_run(
+            function::FORCE_SET_ATTRIBUTE,
+            &(set_as, collection, maybe_item, namespace, key, value),
+        )
+    }
+}
+
+/// Dry run a function, whose output decodes as `O` when it succeeds: `()` for the functions
+/// writing no output.
+fn dry_run<I: Encode, O: Decode>(function: u8, input: &I) -> DryRun<O> {
+    let output = ChainExtensionMethod::build(dry_run_func_id(VERSION, function))
+        .input::<I>()
+        .output::<DryRunResultExt, false>()
+        .handle_error_code::<NftsError>()
+        .call(input);
+    match output {
+        Ok(output) => DryRun {
+            // An output that doesn't decode comes from a runtime unknown to this release.
+            result: NftsError::from_status_code(output.status).and_then(|()| {
+                O::decode(&mut &output.output[..]).map_err(|_| NftsError::UnknownError)
+            }),
+            weight: output.weight,
+        },
+        // The function can't be dry run.
+        Err(e) => DryRun {
+            result: Err(e),
+            weight: WeightExt {
+                ref_time: 0,
+                proof_size: 0,
+            },
+        },
+    }
+}
+
+/// Results of the entries of a batch, from their statuses.
+fn into_results(statuses: Vec<u32>) -> Vec<Result<(), NftsError>> {
+    statuses
+        .into_iter()
+        .map(NftsError::from_status_code)
+        .collect()
 }
@@ -0,0 +1,1165 @@
+//! In-memory simulator of the nfts pallet behind the NFTs chain extension, to unit test the
+//! contracts calling `NftsExtension` in the off-chain environment of ink, without a node.
+//!
+//! [`NftsMock::register`] registers every function id of the current version with
+//! `ink::env::test::register_chain_extension`. The functions return the status codes of the
+//! runtime, reserve the same deposits and apply the same permission rules: the checks of the
+//! pallet calls, the trusted contracts of the privileged functions and the call filter. The
+//! simulated runtime has the ids of `DefaultEnvironment`, see the `environment` module.
+//!
+//! The off-chain environment can't be queried while the extension runs, so the calling contract,
+//! the block number and the balances are set on the mock. Unlike the runtime:
+//! - no weight is charged, a dry run returns a zero weight;
+//! - balances have no existential deposit;
+//! - pages follow the order of the ids, where the runtime follows the order of the storage.
+//!
+//! ```
+//! use ink::env::{test, DefaultEnvironment};
+//! use nfts_extension::mock::NftsMock;
+//! use nfts_extension::types::DefaultCreateInput;
+//! use nfts_extension::{CollectionConfigExt, NftsExtension};
+//!
+//! let accounts = test::default_accounts::<DefaultEnvironment>();
+//! let mock = NftsMock::new();
+//! mock.set_caller(accounts.alice);
+//! mock.set_balance(accounts.alice, 1_000);
+//! mock.register();
+//!
+//! let input = DefaultCreateInput {
+//!     admin: accounts.alice,
+//!     config: CollectionConfigExt::default(),
+//! };
+//! assert_eq!(NftsExtension::create(input), Ok(0));
+//! assert_eq!(
+//!     mock.reserved_balance(accounts.alice),
+//!     mock.constants().collection_deposit
+//! );
+//! ```
+
+use crate::errors::{NftsError, SUCCESS};
+use crate::types::{
+    BatchMintEntry, DefaultCollectionConfigExt, DefaultCollectionDetailsExt, DefaultCreateInput,
+    DefaultNftsConstantsExt,
+};
+use crate::{AccountId, Balance, BlockNumber, CollectionId, ItemId};
+use ink::env::test::{register_chain_extension, ChainExtension};
+use nfts_extension_types::flags::CollectionRolesFlags;
+use nfts_extension_types::ids::{
+    dry_run_func_id, func_id, function, split_func_id, strip_dry_run, FUNCTIONS, VERSION,
+};
+use nfts_extension_types::{
+    AttributeNamespaceExt, BatchModeExt, CapabilitiesExt, CollectionDetailsExt, DryRunResultExt,
+    ItemSettingsExt, MintTypeExt, MintWitnessExt, Page, PalletFeaturesExt, WeightExt,
+    MAX_BATCH_SIZE, MAX_PAGE_SIZE, MAX_REDEPOSIT_ITEMS,
+};
+use scale::{Decode, Encode};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+/// Functions only the trusted contracts may call.
+const PRIVILEGED_FUNCTIONS: &[u8] = &[
+    function::FORCE_CREATE,
+    function::FORCE_MINT,
+    function::FORCE_COLLECTION_OWNER,
+    function::FORCE_COLLECTION_CONFIG,
+    function::FORCE_SET_ATTRIBUTE,
+];
+
+/// Handle on the simulated chain, shared with the functions it registers.
+#[derive(Clone, Default)]
+pub struct NftsMock(Rc<RefCell<State>>);
+
+impl NftsMock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register every function of the extension in the off-chain environment, replacing the ones
+    /// registered before.
+    pub fn register(&self) {
+        for function in FUNCTIONS {
+            for func_id in [
+                func_id(VERSION, *function),
+                dry_run_func_id(VERSION, *function),
+            ] {
+                register_chain_extension(MockedFunction {
+                    func_id,
+                    state: self.0.clone(),
+                });
+            }
+        }
+    }
+
+    /// Set the contract calling the extension, the origin of the pallet calls.
+    pub fn set_caller(&self, contract: AccountId) {
+        self.0.borrow_mut().env.caller = contract;
+    }
+
+    /// Set the block number, checked against the mint settings.
+    pub fn set_block_number(&self, block_number: BlockNumber) {
+        self.0.borrow_mut().env.block_number = block_number;
+    }
+
+    pub fn constants(&self) -> DefaultNftsConstantsExt {
+        self.0.borrow().env.constants
+    }
+
+    /// Set the constants of the pallet, including its enabled features.
+    pub fn set_constants(&self, constants: DefaultNftsConstantsExt) {
+        self.0.borrow_mut().env.constants = constants;
+    }
+
+    /// Let `contract` call the privileged functions, as the `TrustedContracts` of the runtime.
+    pub fn trust(&self, contract: AccountId) {
+        self.0.borrow_mut().env.trusted.insert(contract);
+    }
+
+    /// Decide which contracts may call which functions, as the `CallFilter` of the runtime. The
+    /// filter gets the calling contract and one of the `ids::function` constants.
+    pub fn set_call_filter(&self, filter: impl Fn(&AccountId, u8) -> bool + 'static) {
+        self.0.borrow_mut().filter = Some(Box::new(filter));
+    }
+
+    /// Set the free balance of an account, its reserved balance is kept.
+    pub fn set_balance(&self, account: AccountId, free: Balance) {
+        self.0.borrow_mut().storage.free.insert(account, free);
+    }
+
+    pub fn free_balance(&self, account: AccountId) -> Balance {
+        let state = self.0.borrow();
+        state
+            .storage
+            .free
+            .get(&account)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Balance of an account reserved by the deposits.
+    pub fn reserved_balance(&self, account: AccountId) -> Balance {
+        let state = self.0.borrow();
+        state
+            .storage
+            .reserved
+            .get(&account)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn owner_of(&self, collection: CollectionId, item: ItemId) -> Option<AccountId> {
+        let state = self.0.borrow();
+        state
+            .storage
+            .items
+            .get(&(collection, item))
+            .map(|details| details.owner)
+    }
+
+    /// Value of an attribute of a collection, or of one of its items.
+    pub fn attribute(
+        &self,
+        collection: CollectionId,
+        maybe_item: Option<ItemId>,
+        namespace: AttributeNamespaceExt<AccountId>,
+        key: &[u8],
+    ) -> Option<Vec<u8>> {
+        let state = self.0.borrow();
+        state
+            .storage
+            .attributes
+            .get(&attribute_key(collection, maybe_item, &namespace, key))
+            .cloned()
+    }
+}
+
+/// One function id of the extension, registered in the off-chain environment.
+struct MockedFunction {
+    func_id: u32,
+    state: Rc<RefCell<State>>,
+}
+
+impl ChainExtension for MockedFunction {
+    fn func_id(&self) -> u32 {
+        self.func_id
+    }
+
+    fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+        // The off-chain engine passes the input buffer SCALE encoded as a `Vec<u8>`.
+        let input: Vec<u8> = decode(input);
+        self.state.borrow_mut().call(self.func_id, &input, output)
+    }
+}
+
+/// Context of the calls, set by the tests.
+#[derive(Clone)]
+struct Env {
+    caller: AccountId,
+    block_number: BlockNumber,
+    constants: DefaultNftsConstantsExt,
+    trusted: BTreeSet<AccountId>,
+}
+
+impl Env {
+    fn ensure_trusted(&self) -> Result<(), NftsError> {
+        if self.trusted.contains(&self.caller) {
+            Ok(())
+        } else {
+            Err(NftsError::NoPermission)
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ItemDetails {
+    owner: AccountId,
+    /// Delegates allowed to transfer the item, with the deadline of their approval.
+    approvals: BTreeMap<AccountId, Option<BlockNumber>>,
+    /// Account the deposit is reserved from, and its amount.
+    deposit: (AccountId, Balance),
+}
+
+/// State of the pallet and of the balances.
+#[derive(Clone, Default)]
+struct Storage {
+    next_collection_id: CollectionId,
+    collections: BTreeMap<CollectionId, DefaultCollectionDetailsExt>,
+    collection_configs: BTreeMap<CollectionId, DefaultCollectionConfigExt>,
+    collection_accounts: BTreeSet<(AccountId, CollectionId)>,
+    roles: BTreeMap<(CollectionId, AccountId), CollectionRolesFlags>,
+    items: BTreeMap<(CollectionId, ItemId), ItemDetails>,
+    item_configs: BTreeMap<(CollectionId, ItemId), ItemSettingsExt>,
+    accounts: BTreeSet<(AccountId, CollectionId, ItemId)>,
+    /// Values by their encoded `(collection, maybe_item, namespace, key)`.
+    attributes: BTreeMap<Vec<u8>, Vec<u8>>,
+    free: BTreeMap<AccountId, Balance>,
+    reserved: BTreeMap<AccountId, Balance>,
+}
+
+/// Call filter of the runtime config: whether a contract may call a function.
+type Filter = Box<dyn Fn(&AccountId, u8) -> bool>;
+
+struct State {
+    env: Env,
+    filter: Option<Filter>,
+    storage: Storage,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            env: Env {
+                caller: AccountId::from([0; 32]),
+                block_number: 0,
+                constants: DefaultNftsConstantsExt {
+                    collection_deposit: 100,
+                    item_deposit: 10,
+                    metadata_deposit_base: 10,
+                    attribute_deposit_base: 10,
+                    deposit_per_byte: 1,
+                    string_limit: 256,
+                    key_limit: 64,
+                    value_limit: 256,
+                    approvals_limit: 20,
+                    item_attributes_approvals_limit: 30,
+                    max_tips: 10,
+                    max_deadline_duration: 10_000,
+                    max_attributes_per_call: 10,
+                    features: PalletFeaturesExt {
+                        trading: true,
+                        attributes: true,
+                        approvals: true,
+                        swaps: true,
+                    },
+                },
+                trusted: BTreeSet::new(),
+            },
+            filter: None,
+            storage: Storage::default(),
+        }
+    }
+}
+
+impl State {
+    /// Same steps as the extension of the runtime: dry run flag, call filter, then the function.
+    fn call(&mut self, func_id: u32, input: &[u8], output: &mut Vec<u8>) -> u32 {
+        let (id, dry_run) = strip_dry_run(func_id as u16);
+        let (_, function) = split_func_id(id);
+        if dry_run && !is_extrinsic(function) {
+            return NftsError::UnsupportedFunction.status_code();
+        }
+        if let Some(filter) = &self.filter {
+            if !filter(&self.env.caller, function) {
+                return NftsError::NoPermission.status_code();
+            }
+        }
+        if !dry_run {
+            return self.call_func(function, input, output);
+        }
+
+        let storage = self.storage.clone();
+        let mut function_output = Vec::new();
+        let status = self.call_func(function, input, &mut function_output);
+        self.storage = storage;
+        let result = DryRunResultExt {
+            status,
+            weight: WeightExt {
+                ref_time: 0,
+                proof_size: 0,
+            },
+            output: function_output,
+        };
+        result.encode_to(output);
+        SUCCESS
+    }
+
+    fn call_func(&mut self, function: u8, input: &[u8], output: &mut Vec<u8>) -> u32 {
+        // Like the runtime, the untrusted callers of the privileged functions are rejected before
+        // their input is read.
+        if PRIVILEGED_FUNCTIONS.contains(&function) {
+            if let Err(e) = self.env.ensure_trusted() {
+                return e.status_code();
+            }
+        }
+        match function {
+            function::CREATE => {
+                let input: DefaultCreateInput = decode(input);
+                let result = self.dispatch(|storage, env| storage.create(env, input.admin));
+                respond(result, output)
+            }
+            function::REDEPOSIT => {
+                let (collection, items): (CollectionId, Vec<ItemId>) = decode(input);
+                if items.len() as u32 > MAX_REDEPOSIT_ITEMS {
+                    return NftsError::InputTooLarge.status_code();
+                }
+                let result =
+                    self.dispatch(|storage, env| storage.redeposit(env, collection, items));
+                status(result)
+            }
+            function::BATCH_MINT => {
+                let (collection, entries, mode): (CollectionId, Vec<BatchMintEntry>, BatchModeExt) =
+                    decode(input);
+                if entries.len() as u32 > MAX_BATCH_SIZE {
+                    return NftsError::InputTooLarge.status_code();
+                }
+                let calls = entries
+                    .into_iter()
+                    .map(|(item, mint_to, witness)| {
+                        move |storage: &mut Storage, env: &Env| {
+                            storage.mint(env, collection, item, mint_to, witness)
+                        }
+                    })
+                    .collect();
+                self.dispatch_batch(calls, mode, output)
+            }
+            function::BATCH_TRANSFER => {
+                let (entries, mode): (Vec<(CollectionId, ItemId, AccountId)>, BatchModeExt) =
+                    decode(input);
+                if entries.len() as u32 > MAX_BATCH_SIZE {
+                    return NftsError::InputTooLarge.status_code();
+                }
+                let calls = entries
+                    .into_iter()
+                    .map(|(collection, item, dest)| {
+                        move |storage: &mut Storage, env: &Env| {
+                            storage.transfer(env, collection, item, dest)
+                        }
+                    })
+                    .collect();
+                self.dispatch_batch(calls, mode, output)
+            }
+            function::TRANSFER => {
+                let (collection, item, dest): (CollectionId, ItemId, AccountId) = decode(input);
+                let result =
+                    self.dispatch(|storage, env| storage.transfer(env, collection, item, dest));
+                status(result)
+            }
+            function::APPROVE_TRANSFER => {
+                let (collection, item, delegate, maybe_deadline): (
+                    CollectionId,
+                    ItemId,
+                    AccountId,
+                    Option<BlockNumber>,
+                ) = decode(input);
+                let result = self.dispatch(|storage, env| {
+                    storage.approve_transfer(env, collection, item, delegate, maybe_deadline)
+                });
+                status(result)
+            }
+            function::CANCEL_APPROVAL => {
+                let (collection, item, delegate): (CollectionId, ItemId, AccountId) = decode(input);
+                let result = self.dispatch(|storage, env| {
+                    storage.cancel_approval(env, collection, item, delegate)
+                });
+                status(result)
+            }
+
+            // Privileged extrinsics
+            function::FORCE_CREATE => {
+                let (owner, config): (AccountId, DefaultCollectionConfigExt) = decode(input);
+                let result =
+                    self.dispatch(|storage, _| storage.create_collection(owner, owner, config, 0));
+                respond(result, output)
+            }
+            function::FORCE_MINT => {
+                let (collection, item, mint_to, settings): (
+                    CollectionId,
+                    ItemId,
+                    AccountId,
+                    ItemSettingsExt,
+                ) = decode(input);
+                let result = self.dispatch(|storage, env| {
+                    storage.do_mint(env, collection, item, None, mint_to, settings, |_, _, _| {
+                        Ok(())
+                    })
+                });
+                status(result)
+            }
+            function::FORCE_COLLECTION_OWNER => {
+                let (collection, owner): (CollectionId, AccountId) = decode(input);
+                let result =
+                    self.dispatch(|storage, _| storage.force_collection_owner(collection, owner));
+                status(result)
+            }
+            function::FORCE_COLLECTION_CONFIG => {
+                let (collection, config): (CollectionId, DefaultCollectionConfigExt) =
+                    decode(input);
+                let result = self.dispatch(|storage, _| {
+                    if !storage.collections.contains_key(&collection) {
+                        return Err(NftsError::UnknownCollection);
+                    }
+                    storage.collection_configs.insert(collection, config);
+                    Ok(())
+                });
+                status(result)
+            }
+            function::FORCE_SET_ATTRIBUTE => {
+                // Set as any account, the attributes set by the extension have no deposit.
+                let (_, collection, maybe_item, namespace, key, value): ForceSetAttributeInput =
+                    decode(input);
+                let constants = self.env.constants;
+                if key.len() as u32 > constants.key_limit
+                    || value.len() as u32 > constants.value_limit
+                {
+                    return NftsError::InputTooLarge.status_code();
+                }
+                let result = self.dispatch(|storage, _| {
+                    storage.force_set_attribute(collection, maybe_item, namespace, key, value)
+                });
+                status(result)
+            }
+
+            // Chain state
+            function::GET_COLLECTION => {
+                let collection: CollectionId = decode(input);
+                self.storage
+                    .collections
+                    .get(&collection)
+                    .cloned()
+                    .encode_to(output);
+                SUCCESS
+            }
+            function::GET_OWNED_ITEMS => {
+                let (account, collection, cursor, limit): (
+                    AccountId,
+                    Option<CollectionId>,
+                    Option<(CollectionId, ItemId)>,
+                    u32,
+                ) = decode(input);
+                let limit = limit.min(MAX_PAGE_SIZE);
+                if let (Some(collection), Some((cursor_collection, _))) = (collection, cursor) {
+                    if cursor_collection != collection {
+                        return NftsError::InvalidInput.status_code();
+                    }
+                }
+                let items: Vec<(CollectionId, ItemId)> = self
+                    .storage
+                    .accounts
+                    .iter()
+                    .filter(|(owner, _, _)| *owner == account)
+                    .map(|(_, c, i)| (*c, *i))
+                    .filter(|(c, i)| match (collection, cursor) {
+                        (Some(collection), Some((_, after))) => *c == collection && *i > after,
+                        (Some(collection), None) => *c == collection,
+                        (None, Some(after)) => (*c, *i) > after,
+                        (None, None) => true,
+                    })
+                    .take(limit as usize)
+                    .collect();
+                into_page(items, limit).encode_to(output);
+                SUCCESS
+            }
+            function::GET_COLLECTIONS => {
+                let (cursor, limit): (Option<CollectionId>, u32) = decode(input);
+                let limit = limit.min(MAX_PAGE_SIZE);
+                let collections: Vec<CollectionId> = self
+                    .storage
+                    .collections
+                    .keys()
+                    .filter(|c| cursor.is_none_or(|after| **c > after))
+                    .copied()
+                    .take(limit as usize)
+                    .collect();
+                into_page(collections, limit).encode_to(output);
+                SUCCESS
+            }
+            function::GET_OWNED_COLLECTIONS => {
+                let (owner, cursor, limit): (AccountId, Option<CollectionId>, u32) = decode(input);
+                let limit = limit.min(MAX_PAGE_SIZE);
+                let collections: Vec<CollectionId> = self
+                    .storage
+                    .collection_accounts
+                    .iter()
+                    .filter(|(account, c)| {
+                        *account == owner && cursor.is_none_or(|after| *c > after)
+                    })
+                    .map(|(_, c)| *c)
+                    .take(limit as usize)
+                    .collect();
+                into_page(collections, limit).encode_to(output);
+                SUCCESS
+            }
+            function::GET_COLLECTION_CONFIG => {
+                let collection: CollectionId = decode(input);
+                self.storage
+                    .collection_configs
+                    .get(&collection)
+                    .encode_to(output);
+                SUCCESS
+            }
+            function::GET_ITEM_CONFIG => {
+                let (collection, item): (CollectionId, ItemId) = decode(input);
+                self.storage
+                    .item_configs
+                    .get(&(collection, item))
+                    .encode_to(output);
+                SUCCESS
+            }
+            function::GET_CAPABILITIES => {
+                let capabilities = CapabilitiesExt {
+                    version: VERSION,
+                    functions: FUNCTIONS
+                        .iter()
+                        .map(|function| func_id(VERSION, *function))
+                        .collect(),
+                    features: self.env.constants.features,
+                };
+                capabilities.encode_to(output);
+                SUCCESS
+            }
+            function::GET_ITEM_OWNER => {
+                let (collection, item): (CollectionId, ItemId) = decode(input);
+                self.storage
+                    .items
+                    .get(&(collection, item))
+                    .map(|details| details.owner)
+                    .encode_to(output);
+                SUCCESS
+            }
+            function::GET_BALANCE => {
+                let (collection, owner): (CollectionId, AccountId) = decode(input);
+                let balance = self
+                    .storage
+                    .accounts
+                    .iter()
+                    .filter(|(account, c, _)| *account == owner && *c == collection)
+                    .count() as u32;
+                balance.encode_to(output);
+                SUCCESS
+            }
+            function::GET_APPROVAL => {
+                let (collection, item, delegate): (CollectionId, ItemId, AccountId) = decode(input);
+                self.storage
+                    .items
+                    .get(&(collection, item))
+                    .and_then(|details| details.approvals.get(&delegate).copied())
+                    .encode_to(output);
+                SUCCESS
+            }
+            function::GET_ATTRIBUTE => {
+                let (collection, maybe_item, namespace, key): (
+                    CollectionId,
+                    Option<ItemId>,
+                    AttributeNamespaceExt<AccountId>,
+                    Vec<u8>,
+                ) = decode(input);
+                if key.len() as u32 > self.env.constants.key_limit {
+                    return NftsError::InputTooLarge.status_code();
+                }
+                let value = self
+                    .storage
+                    .attributes
+                    .get(&attribute_key(collection, maybe_item, &namespace, &key))
+                    .cloned();
+                respond(Ok(value), output)
+            }
+
+            // Constants
+            function::GET_APPROVALS_LIMIT => {
+                self.env.constants.approvals_limit.encode_to(output);
+                SUCCESS
+            }
+            function::GET_ATTRIBUTE_DEPOSIT_BASE => {
+                self.env.constants.attribute_deposit_base.encode_to(output);
+                SUCCESS
+            }
+            function::GET_COLLECTION_DEPOSIT => {
+                self.env.constants.collection_deposit.encode_to(output);
+                SUCCESS
+            }
+            function::GET_DEPOSIT_PER_BYTE => {
+                self.env.constants.deposit_per_byte.encode_to(output);
+                SUCCESS
+            }
+            function::GET_NFTS_CONSTANTS => {
+                self.env.constants.encode_to(output);
+                SUCCESS
+            }
+            _ => NftsError::UnsupportedFunction.status_code(),
+        }
+    }
+
+    /// Run a pallet call, its changes are kept only if it succeeds.
+    fn dispatch<R>(
+        &mut self,
+        call: impl FnOnce(&mut Storage, &Env) -> Result<R, NftsError>,
+    ) -> Result<R, NftsError> {
+        let mut storage = self.storage.clone();
+        let result = call(&mut storage, &self.env);
+        if result.is_ok() {
+            self.storage = storage;
+        }
+        result
+    }
+
+    /// Run the calls of a batch as the runtime does, see `NftsExtension::batch_mint`.
+    fn dispatch_batch<F>(&mut self, calls: Vec<F>, mode: BatchModeExt, output: &mut Vec<u8>) -> u32
+    where
+        F: FnOnce(&mut Storage, &Env) -> Result<(), NftsError>,
+    {
+        let statuses = match mode {
+            BatchModeExt::Atomic => {
+                let len = calls.len();
+                let result = self.dispatch(|storage, env| {
+                    calls.into_iter().try_for_each(|call| call(storage, env))
+                });
+                if let Err(e) = result {
+                    return e.status_code();
+                }
+                vec![SUCCESS; len]
+            }
+            BatchModeExt::BestEffort => calls
+                .into_iter()
+                .map(|call| status(self.dispatch(call)))
+                .collect(),
+        };
+        respond(Ok(statuses), output)
+    }
+}
+
+impl Storage {
+    /// `pallet_balances` errors aren't nfts errors, the runtime reports them as `UnknownError`.
+    fn reserve(&mut self, account: &AccountId, amount: Balance) -> Result<(), NftsError> {
+        let free = self.free.entry(*account).or_default();
+        *free = free.checked_sub(amount).ok_or(NftsError::UnknownError)?;
+        let reserved = self.reserved.entry(*account).or_default();
+        *reserved = reserved.saturating_add(amount);
+        Ok(())
+    }
+
+    fn unreserve(&mut self, account: &AccountId, amount: Balance) {
+        let reserved = self.reserved.entry(*account).or_default();
+        let amount = amount.min(*reserved);
+        *reserved -= amount;
+        let free = self.free.entry(*account).or_default();
+        *free = free.saturating_add(amount);
+    }
+
+    /// Move a reserved balance to the reserved balance of another account.
+    fn repatriate_reserved(&mut self, from: &AccountId, to: &AccountId, amount: Balance) {
+        let reserved = self.reserved.entry(*from).or_default();
+        let amount = amount.min(*reserved);
+        *reserved -= amount;
+        let reserved = self.reserved.entry(*to).or_default();
+        *reserved = reserved.saturating_add(amount);
+    }
+
+    fn transfer_balance(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Balance,
+    ) -> Result<(), NftsError> {
+        let free = self.free.entry(*from).or_default();
+        *free = free.checked_sub(amount).ok_or(NftsError::UnknownError)?;
+        let free = self.free.entry(*to).or_default();
+        *free = free.saturating_add(amount);
+        Ok(())
+    }
+
+    fn has_role(&self, collection: CollectionId, account: &AccountId, role: u8) -> bool {
+        self.roles
+            .get(&(collection, *account))
+            .is_some_and(|roles| roles.contains(role))
+    }
+
+    /// `create`: the caller owns the collection and pays its deposit, the admin gets every role.
+    /// Like the runtime, the collection gets the default config whatever the input config.
+    fn create(&mut self, env: &Env, admin: AccountId) -> Result<CollectionId, NftsError> {
+        let deposit = env.constants.collection_deposit;
+        self.create_collection(env.caller, admin, Default::default(), deposit)
+    }
+
+    fn create_collection(
+        &mut self,
+        owner: AccountId,
+        admin: AccountId,
+        config: DefaultCollectionConfigExt,
+        deposit: Balance,
+    ) -> Result<CollectionId, NftsError> {
+        let collection = self.next_collection_id;
+        if self.collections.contains_key(&collection) {
+            return Err(NftsError::CollectionIdInUse);
+        }
+        self.reserve(&owner, deposit)?;
+
+        let details = CollectionDetailsExt {
+            owner,
+            owner_deposit: deposit,
+            items: 0,
+            item_metadatas: 0,
+            item_configs: 0,
+            attributes: 0,
+        };
+        self.collections.insert(collection, details);
+        self.roles.insert(
+            (collection, admin),
+            CollectionRolesFlags::from_bits_truncate(CollectionRolesFlags::ALL),
+        );
+        self.collection_configs.insert(collection, config);
+        self.collection_accounts.insert((owner, collection));
+        self.next_collection_id = collection + 1;
+        Ok(collection)
+    }
+
+    /// `mint`: the issuer mints at any time, anyone else as allowed by the mint settings. The
+    /// caller pays the item deposit and the mint price.
+    fn mint(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        item: ItemId,
+        mint_to: AccountId,
+        witness: Option<MintWitnessExt<ItemId>>,
+    ) -> Result<(), NftsError> {
+        let config = self
+            .collection_configs
+            .get(&collection)
+            .ok_or(NftsError::NoConfig)?;
+        let settings = config.mint_settings.default_item_settings;
+
+        self.do_mint(
+            env,
+            collection,
+            item,
+            Some(env.caller),
+            mint_to,
+            settings,
+            |storage, owner, config| storage.check_mint(env, collection, owner, config, witness),
+        )
+    }
+
+    /// Checks of `mint` against the mint settings, then payment of the mint price.
+    fn check_mint(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        owner: &AccountId,
+        config: &DefaultCollectionConfigExt,
+        witness: Option<MintWitnessExt<ItemId>>,
+    ) -> Result<(), NftsError> {
+        let caller = env.caller;
+        if self.has_role(collection, &caller, CollectionRolesFlags::ISSUER) {
+            return Ok(());
+        }
+        let mint_settings = config.mint_settings;
+        if let Some(start_block) = mint_settings.start_block {
+            if start_block > env.block_number {
+                return Err(NftsError::MintNotStarted);
+            }
+        }
+        if let Some(end_block) = mint_settings.end_block {
+            if end_block < env.block_number {
+                return Err(NftsError::MintEnded);
+            }
+        }
+        match mint_settings.mint_type {
+            MintTypeExt::Issuer => return Err(NftsError::NoPermission),
+            MintTypeExt::HolderOf(holder_of) => {
+                let MintWitnessExt { owned_item } = witness.ok_or(NftsError::BadWitness)?;
+                if !self.accounts.contains(&(caller, holder_of, owned_item)) {
+                    return Err(NftsError::BadWitness);
+                }
+                // `PalletAttributes::UsedToClaim(collection)`, set by the pallet.
+                let mut key = vec![0];
+                collection.encode_to(&mut key);
+                let key = attribute_key(
+                    holder_of,
+                    Some(owned_item),
+                    &AttributeNamespaceExt::Pallet,
+                    &key,
+                );
+                if self.attributes.contains_key(&key) {
+                    return Err(NftsError::AlreadyClaimed);
+                }
+                self.attributes.insert(key, vec![0]);
+            }
+            MintTypeExt::Public => (),
+        }
+        if let Some(price) = mint_settings.price {
+            self.transfer_balance(&caller, owner, price)?;
+        }
+        Ok(())
+    }
+
+    /// Mint an item once `check` passed, its deposit is reserved from `depositor`, or from the
+    /// collection owner.
+    #[allow(clippy::too_many_arguments)]
+    fn do_mint<F>(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        item: ItemId,
+        depositor: Option<AccountId>,
+        mint_to: AccountId,
+        settings: ItemSettingsExt,
+        check: F,
+    ) -> Result<(), NftsError>
+    where
+        F: FnOnce(&mut Self, &AccountId, &DefaultCollectionConfigExt) -> Result<(), NftsError>,
+    {
+        if self.items.contains_key(&(collection, item)) {
+            return Err(NftsError::AlreadyExists);
+        }
+        let mut details = self
+            .collections
+            .get(&collection)
+            .cloned()
+            .ok_or(NftsError::UnknownCollection)?;
+        let config = *self
+            .collection_configs
+            .get(&collection)
+            .ok_or(NftsError::NoConfig)?;
+        check(self, &details.owner, &config)?;
+        if let Some(max_supply) = config.max_supply {
+            if details.items >= max_supply {
+                return Err(NftsError::MaxSupplyReached);
+            }
+        }
+        details.items += 1;
+
+        let deposit = if config.setting.deposit_required {
+            env.constants.item_deposit
+        } else {
+            0
+        };
+        let depositor = depositor.unwrap_or(details.owner);
+        self.accounts.insert((mint_to, collection, item));
+        match self.item_configs.get(&(collection, item)) {
+            Some(existing) if *existing != settings => {
+                return Err(NftsError::InconsistentItemConfig)
+            }
+            Some(_) => (),
+            None => {
+                self.item_configs.insert((collection, item), settings);
+                details.item_configs += 1;
+            }
+        }
+        self.reserve(&depositor, deposit)?;
+
+        self.items.insert(
+            (collection, item),
+            ItemDetails {
+                owner: mint_to,
+                approvals: BTreeMap::new(),
+                deposit: (depositor, deposit),
+            },
+        );
+        self.collections.insert(collection, details);
+        Ok(())
+    }
+
+    /// `transfer`: the owner of the item or an approved delegate can transfer it, the deposit stays
+    /// reserved from the account that paid it. The approvals are cleared.
+    fn transfer(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        item: ItemId,
+        dest: AccountId,
+    ) -> Result<(), NftsError> {
+        let config = self
+            .collection_configs
+            .get(&collection)
+            .ok_or(NftsError::NoConfig)?;
+        if !config.setting.transferable_items {
+            return Err(NftsError::ItemsNonTransferable);
+        }
+        let settings = self
+            .item_configs
+            .get(&(collection, item))
+            .ok_or(NftsError::UnknownItem)?;
+        if !settings.transferable {
+            return Err(NftsError::ItemLocked);
+        }
+        if !self.collections.contains_key(&collection) {
+            return Err(NftsError::UnknownCollection);
+        }
+        let details = self
+            .items
+            .get_mut(&(collection, item))
+            .ok_or(NftsError::UnknownItem)?;
+        if details.owner != env.caller {
+            let deadline = details
+                .approvals
+                .get(&env.caller)
+                .ok_or(NftsError::NoPermission)?;
+            if deadline.is_some_and(|deadline| env.block_number > deadline) {
+                return Err(NftsError::ApprovalExpired);
+            }
+        }
+
+        let owner = details.owner;
+        details.owner = dest;
+        details.approvals.clear();
+        self.accounts.remove(&(owner, collection, item));
+        self.accounts.insert((dest, collection, item));
+        Ok(())
+    }
+
+    /// `approve_transfer`: the owner of an item lets `delegate` transfer it, until `maybe_deadline`
+    /// blocks from now if any.
+    fn approve_transfer(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        item: ItemId,
+        delegate: AccountId,
+        maybe_deadline: Option<BlockNumber>,
+    ) -> Result<(), NftsError> {
+        if !env.constants.features.approvals {
+            return Err(NftsError::MethodDisabled);
+        }
+        if !self.items.contains_key(&(collection, item)) {
+            return Err(NftsError::UnknownItem);
+        }
+        let config = self
+            .collection_configs
+            .get(&collection)
+            .ok_or(NftsError::NoConfig)?;
+        if !config.setting.transferable_items {
+            return Err(NftsError::ItemsNonTransferable);
+        }
+        let details = self
+            .items
+            .get_mut(&(collection, item))
+            .ok_or(NftsError::UnknownItem)?;
+        if details.owner != env.caller {
+            return Err(NftsError::NoPermission);
+        }
+
+        let deadline = maybe_deadline.map(|deadline| deadline.saturating_add(env.block_number));
+        if details.approvals.len() as u32 >= env.constants.approvals_limit
+            && !details.approvals.contains_key(&delegate)
+        {
+            return Err(NftsError::ReachedApprovalLimit);
+        }
+        details.approvals.insert(delegate, deadline);
+        Ok(())
+    }
+
+    /// `cancel_approval`: the owner of an item revokes an approval, anyone can once it expired.
+    fn cancel_approval(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        item: ItemId,
+        delegate: AccountId,
+    ) -> Result<(), NftsError> {
+        let details = self
+            .items
+            .get_mut(&(collection, item))
+            .ok_or(NftsError::UnknownCollection)?;
+        let deadline = details
+            .approvals
+            .get(&delegate)
+            .ok_or(NftsError::NotDelegate)?;
+        let is_past_deadline = deadline.is_some_and(|deadline| env.block_number > deadline);
+        if !is_past_deadline && details.owner != env.caller {
+            return Err(NftsError::NoPermission);
+        }
+        details.approvals.remove(&delegate);
+        Ok(())
+    }
+
+    /// `redeposit`: the collection owner re-evaluates the deposits of its items, the ones whose
+    /// deposit can't be reserved are skipped.
+    fn redeposit(
+        &mut self,
+        env: &Env,
+        collection: CollectionId,
+        items: Vec<ItemId>,
+    ) -> Result<(), NftsError> {
+        let details = self
+            .collections
+            .get(&collection)
+            .ok_or(NftsError::UnknownCollection)?;
+        if details.owner != env.caller {
+            return Err(NftsError::NoPermission);
+        }
+        let config = self
+            .collection_configs
+            .get(&collection)
+            .ok_or(NftsError::NoConfig)?;
+        let deposit = if config.setting.deposit_required {
+            env.constants.item_deposit
+        } else {
+            0
+        };
+
+        for item in items {
+            let (depositor, old) = match self.items.get(&(collection, item)) {
+                Some(details) => details.deposit,
+                None => continue,
+            };
+            if old > deposit {
+                self.unreserve(&depositor, old - deposit);
+            } else if deposit > old {
+                if self.reserve(&depositor, deposit - old).is_err() {
+                    continue;
+                }
+            } else {
+                continue;
+            }
+            if let Some(details) = self.items.get_mut(&(collection, item)) {
+                details.deposit.1 = deposit;
+            }
+        }
+        Ok(())
+    }
+
+    /// `force_collection_owner`: the collection deposit moves to the new owner.
+    fn force_collection_owner(
+        &mut self,
+        collection: CollectionId,
+        owner: AccountId,
+    ) -> Result<(), NftsError> {
+        let details = self
+            .collections
+            .get_mut(&collection)
+            .ok_or(NftsError::UnknownCollection)?;
+        if details.owner == owner {
+            return Ok(());
+        }
+        let (old_owner, deposit) = (details.owner, details.owner_deposit);
+        details.owner = owner;
+
+        self.repatriate_reserved(&old_owner, &owner, deposit);
+        self.collection_accounts.remove(&(old_owner, collection));
+        self.collection_accounts.insert((owner, collection));
+        Ok(())
+    }
+
+    /// `force_set_attribute`: the attribute is set without a deposit, on a collection or an item.
+    fn force_set_attribute(
+        &mut self,
+        collection: CollectionId,
+        maybe_item: Option<ItemId>,
+        namespace: AttributeNamespaceExt<AccountId>,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), NftsError> {
+        let details = self
+            .collections
+            .get_mut(&collection)
+            .ok_or(NftsError::UnknownCollection)?;
+        let key = attribute_key(collection, maybe_item, &namespace, &key);
+        if self.attributes.insert(key, value).is_none() {
+            details.attributes += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the function dispatches a call of the pallet, and so can be dry run.
+fn is_extrinsic(function: u8) -> bool {
+    matches!(
+        function,
+        function::CREATE
+            | function::REDEPOSIT
+            | function::BATCH_MINT
+            | function::BATCH_TRANSFER
+            | function::TRANSFER
+            | function::APPROVE_TRANSFER
+            | function::CANCEL_APPROVAL
+            | function::FORCE_CREATE
+            | function::FORCE_MINT
+            | function::FORCE_COLLECTION_OWNER
+            | function::FORCE_COLLECTION_CONFIG
+            | function::FORCE_SET_ATTRIBUTE
+    )
+}
+
+/// Input of `force_set_attribute`.
+type ForceSetAttributeInput = (
+    Option<AccountId>,
+    CollectionId,
+    Option<ItemId>,
+    AttributeNamespaceExt<AccountId>,
+    Vec<u8>,
+    Vec<u8>,
+);
+
+/// The runtime traps on an input it can't decode, the contract panics alike.
+fn decode<T: Decode>(mut input: &[u8]) -> T {
+    T::decode(&mut input).expect("NftsMock: invalid input")
+}
+
+/// Status of an extrinsic without a value, which writes no output like the runtime.
+fn status(result: Result<(), NftsError>) -> u32 {
+    match result {
+        Ok(()) => SUCCESS,
+        Err(e) => e.status_code(),
+    }
+}
+
+/// Write the value of an extrinsic to the output and return its status.
+fn respond<T: Encode>(result: Result<T, NftsError>, output: &mut Vec<u8>) -> u32 {
+    match result {
+        Ok(value) => {
+            Ok::<T, NftsError>(value).encode_to(output);
+            SUCCESS
+        }
+        Err(e) => e.status_code(),
+    }
+}
+
+/// Key of an attribute in the storage of the mock.
+fn attribute_key(
+    collection: CollectionId,
+    maybe_item: Option<ItemId>,
+    namespace: &AttributeNamespaceExt<AccountId>,
+    key: &[u8],
+) -> Vec<u8> {
+    (collection, maybe_item, namespace, key).encode()
+}
+
+/// Build a page as the runtime does: a full page has a cursor to the next one.
+fn into_page<Item: Clone>(items: Vec<Item>, limit: u32) -> Page<Item, Item> {
+    let next = if items.len() as u32 == limit {
+        items.last().cloned()
+    } else {
+        None
+    };
+    Page { items, next }
+}
@@ -0,0 +1,317 @@
+//! PSP34 standard backed by a collection of the nfts pallet.
+//!
+//! [`Psp34Collection`] answers the PSP34 messages from the pallet storage and dispatches the
+//! transfers to the pallet, so the contract keeps no copy of the ownership: the items stay visible
+//! and tradable on the pallet, and the contract only stores the collection id and the operators.
+//!
+//! The extension dispatches the pallet calls with the contract as origin, not its caller. So the
+//! contract can only transfer the items it owns or has been approved to transfer on the pallet:
+//! holders who keep their items approve the contract on the pallet once. The contract can't
+//! approve delegates on the pallet for them, so [`Psp34Collection::approve`] keeps the operators
+//! in the contract storage instead. [`Psp34Collection::transfer`] then checks that the PSP34
+//! caller is the owner or one of its operators, approved through the contract or on the pallet,
+//! before moving an item.
+//!
+//! The ids of the pallet are exposed as the PSP34 ids of the same type, `Id::U32` for the `u32` ids
+//! of the default environment. Any other variant is an unknown token.
+//!
+//! ```ignore
+//! #[ink::contract]
+//! mod collection {
+//!     use ink::prelude::vec::Vec;
+//!     use nfts_extension::psp34::{Id, Psp34Collection, PSP34Error, PSP34};
+//!     use nfts_extension::CollectionId;
+//!
+//!     #[ink(storage)]
+//!     pub struct Collection {
+//!         nfts: Psp34Collection,
+//!     }
+//!
+//!     impl Collection {
+//!         #[ink(constructor)]
+//!         pub fn new(collection: CollectionId) -> Self {
+//!             Self {
+//!                 nfts: Psp34Collection::new(collection),
+//!             }
+//!         }
+//!     }
+//!
+//!     impl PSP34 for Collection {
+//!         #[ink(message)]
+//!         fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+//!             self.nfts.transfer(self.env().caller(), to, id, data)
+//!         }
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use crate::errors::NftsError;
+use crate::{AccountId, AttributeNamespaceExt, Balance, NftsEnvironment, NftsExtensionFor};
+use ink::env::DefaultEnvironment;
+use ink::prelude::format;
+use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
+use ink::storage::Mapping;
+use scale::{Decode, Encode, EncodeLike};
+
+/// Id of a token, as defined by PSP34.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Id {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Bytes(Vec<u8>),
+}
+
+/// Errors of the PSP34 messages, as defined by PSP34.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP34Error {
+    /// Any other error, here the ones of the nfts pallet.
+    Custom(String),
+    /// The caller tried to approve itself.
+    SelfApprove,
+    /// The caller isn't allowed to transfer the token.
+    NotApproved,
+    /// The token already exists.
+    TokenExists,
+    /// The token doesn't exist.
+    TokenNotExists,
+    /// The receiver rejected the transfer.
+    SafeTransferCheckFailed(String),
+}
+
+impl From<NftsError> for PSP34Error {
+    fn from(error: NftsError) -> Self {
+        match error {
+            NftsError::NoPermission | NftsError::Unapproved | NftsError::ApprovalExpired => {
+                PSP34Error::NotApproved
+            }
+            NftsError::UnknownItem => PSP34Error::TokenNotExists,
+            NftsError::AlreadyExists => PSP34Error::TokenExists,
+            e => PSP34Error::Custom(format!("{:?}", e)),
+        }
+    }
+}
+
+/// Messages of the PSP34 standard.
+#[ink::trait_definition]
+pub trait PSP34 {
+    /// Id of the collection.
+    #[ink(message)]
+    fn collection_id(&self) -> Id;
+
+    /// Number of tokens owned by `owner`.
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId) -> u32;
+
+    /// Owner of a token, `None` if it doesn't exist.
+    #[ink(message)]
+    fn owner_of(&self, id: Id) -> Option<AccountId>;
+
+    /// Whether `operator` may transfer the token `id` of `owner`, or all of its tokens when `id`
+    /// is `None`.
+    #[ink(message)]
+    fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool;
+
+    /// Let `operator` transfer the token `id` of the caller, or all of its tokens when `id` is
+    /// `None`, or revoke it.
+    #[ink(message)]
+    fn approve(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        approved: bool,
+    ) -> Result<(), PSP34Error>;
+
+    /// Transfer a token owned by the caller, or that it's allowed to transfer, to `to`.
+    #[ink(message)]
+    fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error>;
+
+    /// Number of tokens of the collection.
+    #[ink(message)]
+    fn total_supply(&self) -> Balance;
+}
+
+/// Metadata extension of the PSP34 standard.
+#[ink::trait_definition]
+pub trait PSP34Metadata {
+    /// Value of an attribute of a token, `None` if it isn't set.
+    #[ink(message)]
+    fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>>;
+}
+
+/// Implementation of the PSP34 messages over a single collection of the nfts pallet, to be called
+/// from the [`PSP34`] and [`PSP34Metadata`] messages of the contract. It's stored by the contract,
+/// for the contracts of the environment `E`.
+#[ink::storage_item]
+#[derive(Debug)]
+pub struct Psp34Collection<E: NftsEnvironment = DefaultEnvironment> {
+    collection: E::CollectionId,
+    /// Operators approved through the contract, by owner, operator and token. A `None` token
+    /// approves the operator for all the tokens of the owner.
+    operators: Operators<E::AccountId>,
+}
+
+/// Approvals of an operator by its owner, for a token or all of them with `None`.
+type Operators<AccountId> = Mapping<(AccountId, AccountId, Option<Id>), ()>;
+
+impl<E> Psp34Collection<E>
+where
+    E: NftsEnvironment,
+    E::AccountId: EncodeLike,
+    E::CollectionId: Into<Id>,
+    E::ItemId: TryFrom<Id, Error = PSP34Error>,
+{
+    pub fn new(collection: E::CollectionId) -> Self {
+        Self {
+            collection,
+            operators: Mapping::default(),
+        }
+    }
+
+    pub fn collection(&self) -> E::CollectionId {
+        self.collection
+    }
+
+    pub fn collection_id(&self) -> Id {
+        self.collection.into()
+    }
+
+    pub fn balance_of(&self, owner: E::AccountId) -> u32 {
+        NftsExtensionFor::<E>::get_balance(self.collection, owner).unwrap_or_default()
+    }
+
+    pub fn owner_of(&self, id: Id) -> Option<E::AccountId> {
+        let item = E::ItemId::try_from(id).ok()?;
+        NftsExtensionFor::<E>::get_item_owner(self.collection, item)
+            .ok()
+            .flatten()
+    }
+
+    /// Whether `operator` may transfer the token `id` of `owner`: approved through the contract
+    /// for all the tokens of `owner` or for this one, or approved on the pallet for this one and
+    /// the approval hasn't expired. Without an `id`, only the approvals for all the tokens count.
+    pub fn allowance(&self, owner: E::AccountId, operator: E::AccountId, id: Option<Id>) -> bool {
+        if self
+            .operators
+            .contains((owner.clone(), operator.clone(), None::<Id>))
+        {
+            return true;
+        }
+        let item = match id.clone().map(E::ItemId::try_from) {
+            Some(Ok(item)) => item,
+            _ => return false,
+        };
+        if NftsExtensionFor::<E>::get_item_owner(self.collection, item) != Ok(Some(owner.clone())) {
+            return false;
+        }
+        if self.operators.contains((owner, operator.clone(), id)) {
+            return true;
+        }
+        match NftsExtensionFor::<E>::get_approval(self.collection, item, operator) {
+            Ok(Some(Some(deadline))) => ink::env::block_number::<E>() <= deadline,
+            Ok(Some(None)) => true,
+            _ => false,
+        }
+    }
+
+    /// Approve `operator` to transfer the token `id` of `caller`, or all of its tokens when `id` is
+    /// `None`, or cancel the approval. The operators are kept by the contract, see the module
+    /// documentation. `caller` must own the token `id` or `NotApproved` is returned.
+    pub fn approve(
+        &mut self,
+        caller: E::AccountId,
+        operator: E::AccountId,
+        id: Option<Id>,
+        approved: bool,
+    ) -> Result<(), PSP34Error> {
+        if operator == caller {
+            return Err(PSP34Error::SelfApprove);
+        }
+        if let Some(id) = &id {
+            let item = E::ItemId::try_from(id.clone())?;
+            let owner = NftsExtensionFor::<E>::get_item_owner(self.collection, item)?
+                .ok_or(PSP34Error::TokenNotExists)?;
+            if owner != caller {
+                return Err(PSP34Error::NotApproved);
+            }
+        }
+        if approved {
+            self.operators.insert((caller, operator, id), &());
+        } else {
+            self.operators.remove((caller, operator, id));
+        }
+        Ok(())
+    }
+
+    /// Transfer the token `id` to `to` on behalf of `caller`, who must own it or be one of its
+    /// operators. The approval of `caller` for this token through the contract is used up. `data`
+    /// is ignored.
+    pub fn transfer(
+        &mut self,
+        caller: E::AccountId,
+        to: E::AccountId,
+        id: Id,
+        _data: Vec<u8>,
+    ) -> Result<(), PSP34Error> {
+        let item = E::ItemId::try_from(id.clone())?;
+        let owner = NftsExtensionFor::<E>::get_item_owner(self.collection, item)?
+            .ok_or(PSP34Error::TokenNotExists)?;
+        if caller != owner && !self.allowance(owner.clone(), caller.clone(), Some(id.clone())) {
+            return Err(PSP34Error::NotApproved);
+        }
+        NftsExtensionFor::<E>::transfer(self.collection, item, to)?;
+        self.operators.remove((owner, caller, Some(id)));
+        Ok(())
+    }
+
+    pub fn total_supply(&self) -> E::Balance {
+        NftsExtensionFor::<E>::get_collection(self.collection)
+            .map_or(0u32.into(), |details| details.items.into())
+    }
+
+    /// Attribute of a token set by the owner of the collection.
+    pub fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
+        let item = E::ItemId::try_from(id).ok()?;
+        NftsExtensionFor::<E>::get_attribute(
+            self.collection,
+            Some(item),
+            AttributeNamespaceExt::CollectionOwner,
+            key,
+        )
+        .ok()
+        .flatten()
+    }
+}
+
+/// Conversions between the ids of the pallet and the PSP34 ids of the matching type. Any other
+/// PSP34 id is an unknown token.
+macro_rules! id_conversions {
+    ($($id:ty => $variant:ident),*) => {
+        $(
+            impl From<$id> for Id {
+                fn from(id: $id) -> Self {
+                    Id::$variant(id)
+                }
+            }
+
+            impl TryFrom<Id> for $id {
+                type Error = PSP34Error;
+
+                fn try_from(id: Id) -> Result<Self, Self::Error> {
+                    match id {
+                        Id::$variant(id) => Ok(id),
+                        _ => Err(PSP34Error::TokenNotExists),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+id_conversions!(u32 => U32, u64 => U64, u128 => U128);
@@ -0,0 +1,450 @@
+//! Calls of the extension methods against the simulator of the `mock` module, checking the outputs
+//! written like the runtime's are decoded back.
+
+use crate::errors::NftsError;
+use crate::mock::NftsMock;
+use crate::psp34::{Id, PSP34Error, Psp34Collection};
+use crate::types::{DefaultCreateInput, DryRun};
+use crate::{
+    ids, AccountId, AttributeNamespaceExt, BatchModeExt, CollectionConfigExt, MintWitnessExt,
+    NftsExtension, WeightExt, MAX_REDEPOSIT_ITEMS,
+};
+use ink::env::test::{default_accounts, DefaultAccounts};
+use ink::env::DefaultEnvironment;
+
+/// A mock called by `alice`, funded for the deposits.
+fn setup() -> (NftsMock, DefaultAccounts<DefaultEnvironment>) {
+    let accounts = default_accounts::<DefaultEnvironment>();
+    let mock = NftsMock::new();
+    mock.set_caller(accounts.alice);
+    mock.set_balance(accounts.alice, 1_000_000);
+    mock.register();
+    (mock, accounts)
+}
+
+fn create_input(admin: AccountId) -> DefaultCreateInput {
+    DefaultCreateInput {
+        admin,
+        config: CollectionConfigExt::default(),
+    }
+}
+
+#[ink::test]
+fn create_returns_the_collection_ids() {
+    let (_, accounts) = setup();
+
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(1));
+}
+
+#[ink::test]
+fn create_ignores_the_input_config() {
+    let (_mock, accounts) = setup();
+    let input = DefaultCreateInput {
+        admin: accounts.alice,
+        config: CollectionConfigExt::fully_locked(),
+    };
+    assert_eq!(NftsExtension::create(input), Ok(0));
+    assert_eq!(
+        NftsExtension::get_collection_config(0),
+        Ok(Some(CollectionConfigExt::default()))
+    );
+}
+
+#[ink::test]
+fn force_create_returns_the_collection_ids() {
+    let (mock, accounts) = setup();
+    mock.trust(accounts.alice);
+
+    let config = CollectionConfigExt::default();
+    assert_eq!(NftsExtension::force_create(accounts.bob, config), Ok(0));
+    assert_eq!(NftsExtension::force_create(accounts.bob, config), Ok(1));
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(2));
+}
+
+#[ink::test]
+fn redeposit_rejects_too_many_items() {
+    let (_mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+
+    let items: Vec<u32> = (0..MAX_REDEPOSIT_ITEMS).collect();
+    assert_eq!(NftsExtension::redeposit(0, items.clone()), Ok(()));
+    let items = [items, vec![MAX_REDEPOSIT_ITEMS]].concat();
+    assert_eq!(
+        NftsExtension::redeposit(0, items),
+        Err(NftsError::InputTooLarge)
+    );
+}
+
+#[ink::test]
+fn batch_mint_best_effort_returns_every_status() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+
+    let items = vec![
+        (1, accounts.bob, None),
+        (1, accounts.bob, None),
+        (2, accounts.charlie, None),
+    ];
+    assert_eq!(
+        NftsExtension::batch_mint(0, items, BatchModeExt::BestEffort),
+        Ok(vec![Ok(()), Err(NftsError::AlreadyExists), Ok(())])
+    );
+    assert_eq!(mock.owner_of(0, 1), Some(accounts.bob));
+    assert_eq!(mock.owner_of(0, 2), Some(accounts.charlie));
+}
+
+#[ink::test]
+fn batch_mint_atomic_rolls_back_on_failure() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+
+    let items = vec![(1, accounts.bob, None), (2, accounts.bob, None)];
+    assert_eq!(
+        NftsExtension::batch_mint(0, items, BatchModeExt::Atomic),
+        Ok(vec![Ok(()), Ok(())])
+    );
+
+    let items = vec![(3, accounts.bob, None), (1, accounts.bob, None)];
+    assert_eq!(
+        NftsExtension::batch_mint(0, items, BatchModeExt::Atomic),
+        Err(NftsError::AlreadyExists)
+    );
+    assert_eq!(mock.owner_of(0, 3), None);
+}
+
+#[ink::test]
+fn batch_transfer_best_effort_returns_every_status() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.alice, None), (2, accounts.alice, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+    let items = vec![
+        (0, 1, accounts.bob),
+        (0, 3, accounts.bob),
+        (0, 2, accounts.charlie),
+    ];
+    assert_eq!(
+        NftsExtension::batch_transfer(items, BatchModeExt::BestEffort),
+        Ok(vec![Ok(()), Err(NftsError::UnknownItem), Ok(())])
+    );
+    assert_eq!(mock.owner_of(0, 1), Some(accounts.bob));
+    assert_eq!(mock.owner_of(0, 2), Some(accounts.charlie));
+}
+
+#[ink::test]
+fn batch_transfer_atomic_rolls_back_on_failure() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.alice, None), (2, accounts.alice, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+    let items = vec![(0, 1, accounts.bob), (0, 2, accounts.bob)];
+    assert_eq!(
+        NftsExtension::batch_transfer(items, BatchModeExt::Atomic),
+        Ok(vec![Ok(()), Ok(())])
+    );
+
+    // The first transfer succeeds and is rolled back with the second.
+    mock.set_caller(accounts.bob);
+    let items = vec![(0, 1, accounts.charlie), (0, 3, accounts.charlie)];
+    assert_eq!(
+        NftsExtension::batch_transfer(items, BatchModeExt::Atomic),
+        Err(NftsError::UnknownItem)
+    );
+    assert_eq!(mock.owner_of(0, 1), Some(accounts.bob));
+}
+
+#[ink::test]
+fn get_attribute_returns_the_value() {
+    let (mock, accounts) = setup();
+    mock.trust(accounts.alice);
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    assert!(
+        NftsExtension::batch_mint(0, vec![(1, accounts.bob, None)], BatchModeExt::Atomic).is_ok()
+    );
+
+    let namespace = AttributeNamespaceExt::CollectionOwner;
+    assert_eq!(
+        NftsExtension::force_set_attribute(
+            None,
+            0,
+            Some(1),
+            namespace,
+            b"name".to_vec(),
+            b"Song".to_vec()
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        NftsExtension::get_attribute(0, Some(1), namespace, b"name".to_vec()),
+        Ok(Some(b"Song".to_vec()))
+    );
+    assert_eq!(
+        NftsExtension::get_attribute(0, Some(1), namespace, b"genre".to_vec()),
+        Ok(None)
+    );
+
+    let psp34 = Psp34Collection::<DefaultEnvironment>::new(0);
+    assert_eq!(
+        psp34.get_attribute(Id::U32(1), b"name".to_vec()),
+        Some(b"Song".to_vec())
+    );
+}
+
+#[ink::test]
+fn psp34_keeps_the_operators_in_the_contract() {
+    let (mock, accounts) = setup();
+    let contract = accounts.alice;
+    assert_eq!(NftsExtension::create(create_input(contract)), Ok(0));
+    let items = vec![(1, contract, None), (2, accounts.bob, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+    let mut psp34 = Psp34Collection::<DefaultEnvironment>::new(0);
+    let (holder, operator) = (accounts.bob, accounts.charlie);
+    assert_eq!(
+        psp34.approve(holder, holder, Some(Id::U32(2)), true),
+        Err(PSP34Error::SelfApprove)
+    );
+    assert_eq!(
+        psp34.approve(holder, operator, Some(Id::U32(1)), true),
+        Err(PSP34Error::NotApproved)
+    );
+    assert_eq!(
+        psp34.approve(holder, operator, Some(Id::U32(3)), true),
+        Err(PSP34Error::TokenNotExists)
+    );
+    assert_eq!(
+        psp34.approve(holder, operator, Some(Id::U32(2)), true),
+        Ok(())
+    );
+    assert!(psp34.allowance(holder, operator, Some(Id::U32(2))));
+    assert!(!psp34.allowance(holder, operator, None));
+    assert_eq!(NftsExtension::get_approval(0, 2, operator), Ok(None));
+
+    // The contract moves the item once the holder approved it on the pallet.
+    let transfer = |psp34: &mut Psp34Collection| {
+        psp34.transfer(operator, accounts.django, Id::U32(2), Vec::new())
+    };
+    assert_eq!(transfer(&mut psp34), Err(PSP34Error::NotApproved));
+    mock.set_caller(holder);
+    assert_eq!(
+        NftsExtension::approve_transfer(0, 2, contract, None),
+        Ok(())
+    );
+    mock.set_caller(contract);
+    assert_eq!(transfer(&mut psp34), Ok(()));
+    assert_eq!(psp34.owner_of(Id::U32(2)), Some(accounts.django));
+    assert!(!psp34.allowance(holder, operator, Some(Id::U32(2))));
+
+    // An operator of all the tokens of an owner.
+    assert_eq!(psp34.approve(accounts.django, operator, None, true), Ok(()));
+    assert!(psp34.allowance(accounts.django, operator, None));
+    assert!(psp34.allowance(accounts.django, operator, Some(Id::U32(2))));
+    assert_eq!(
+        psp34.approve(accounts.django, operator, None, false),
+        Ok(())
+    );
+    assert!(!psp34.allowance(accounts.django, operator, Some(Id::U32(2))));
+}
+
+#[ink::test]
+fn getters_return_the_status_of_the_runtime() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::get_item_owner(0, 1), Ok(None));
+
+    mock.set_call_filter(|_, function| function != ids::function::GET_ITEM_OWNER);
+    assert_eq!(
+        NftsExtension::get_item_owner(0, 1),
+        Err(NftsError::NoPermission)
+    );
+    assert_eq!(NftsExtension::get_balance(0, accounts.alice), Ok(0));
+}
+
+#[ink::test]
+fn get_owned_items_continues_from_the_cursor() {
+    let (_mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.bob, None), (2, accounts.bob, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+    let page = NftsExtension::get_owned_items(accounts.bob, Some(0), None, 1).unwrap();
+    assert_eq!(page.items, vec![(0, 1)]);
+    let next = NftsExtension::get_owned_items(accounts.bob, Some(0), page.next, 1).unwrap();
+    assert_eq!(next.items, vec![(0, 2)]);
+
+    // The cursor must be in the queried collection.
+    assert_eq!(
+        NftsExtension::get_owned_items(accounts.bob, Some(1), page.next, 1),
+        Err(NftsError::InvalidInput)
+    );
+}
+
+#[ink::test]
+fn mock_applies_the_permission_rules() {
+    let (mock, accounts) = setup();
+    let config = CollectionConfigExt::default();
+    assert_eq!(
+        NftsExtension::force_create(accounts.bob, config),
+        Err(NftsError::NoPermission)
+    );
+
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.alice, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+    // Only the issuer mints, only the owner or a delegate transfers.
+    mock.set_caller(accounts.bob);
+    mock.set_balance(accounts.bob, 1_000);
+    assert_eq!(
+        NftsExtension::batch_mint(0, vec![(2, accounts.bob, None)], BatchModeExt::Atomic),
+        Err(NftsError::NoPermission)
+    );
+    assert_eq!(
+        NftsExtension::transfer(0, 1, accounts.bob),
+        Err(NftsError::NoPermission)
+    );
+
+    mock.set_caller(accounts.alice);
+    assert_eq!(
+        NftsExtension::approve_transfer(0, 1, accounts.bob, None),
+        Ok(())
+    );
+    mock.set_caller(accounts.bob);
+    assert_eq!(NftsExtension::transfer(0, 1, accounts.charlie), Ok(()));
+    assert_eq!(mock.owner_of(0, 1), Some(accounts.charlie));
+}
+
+#[ink::test]
+fn mock_reserves_the_deposits() {
+    let (mock, accounts) = setup();
+    let constants = mock.constants();
+
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.bob, None), (2, accounts.bob, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+    let reserved = constants.collection_deposit + 2 * constants.item_deposit;
+    assert_eq!(mock.reserved_balance(accounts.alice), reserved);
+    assert_eq!(mock.free_balance(accounts.alice), 1_000_000 - reserved);
+
+    // `pallet_balances` fails to reserve, reported as an unknown error.
+    mock.set_caller(accounts.bob);
+    assert_eq!(
+        NftsExtension::create(create_input(accounts.bob)),
+        Err(NftsError::UnknownError)
+    );
+    assert_eq!(NftsExtension::get_collection(1), None);
+}
+
+#[ink::test]
+fn mock_lets_each_holder_item_claim_once() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.bob, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+    mock.trust(accounts.alice);
+    let config = CollectionConfigExt::holder_gated(0);
+    assert_eq!(NftsExtension::force_create(accounts.alice, config), Ok(1));
+
+    mock.set_caller(accounts.bob);
+    mock.set_balance(accounts.bob, 1_000);
+    let witness = Some(MintWitnessExt { owned_item: 1 });
+    let mint = |item, witness| {
+        NftsExtension::batch_mint(1, vec![(item, accounts.bob, witness)], BatchModeExt::Atomic)
+    };
+    assert_eq!(mint(1, None), Err(NftsError::BadWitness));
+    assert_eq!(
+        mint(1, Some(MintWitnessExt { owned_item: 2 })),
+        Err(NftsError::BadWitness)
+    );
+    assert_eq!(mint(1, witness), Ok(vec![Ok(())]));
+    assert_eq!(mint(2, witness), Err(NftsError::AlreadyClaimed));
+
+    // The claim stays with the item, not with its holder.
+    mock.set_caller(accounts.charlie);
+    mock.set_balance(accounts.charlie, 1_000);
+    assert_eq!(
+        NftsExtension::batch_mint(
+            1,
+            vec![(2, accounts.charlie, witness)],
+            BatchModeExt::Atomic
+        ),
+        Err(NftsError::BadWitness)
+    );
+}
+
+#[ink::test]
+fn mock_dry_run_changes_nothing() {
+    let (mock, accounts) = setup();
+    let zero = WeightExt {
+        ref_time: 0,
+        proof_size: 0,
+    };
+
+    assert_eq!(
+        NftsExtension::dry_run_create(create_input(accounts.alice)),
+        DryRun {
+            result: Ok(0),
+            weight: zero
+        }
+    );
+    assert_eq!(NftsExtension::get_collection(0), None);
+    assert_eq!(mock.reserved_balance(accounts.alice), 0);
+
+    assert_eq!(
+        NftsExtension::dry_run_transfer(0, 1, accounts.bob),
+        DryRun {
+            result: Err(NftsError::NoConfig),
+            weight: zero
+        }
+    );
+}
+
+#[ink::test]
+fn dry_run_batch_returns_every_status() {
+    let (mock, accounts) = setup();
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+    let items = vec![(1, accounts.alice, None)];
+    assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+    let items = vec![(2, accounts.bob, None), (1, accounts.bob, None)];
+    assert_eq!(
+        NftsExtension::dry_run_batch_mint(0, items, BatchModeExt::BestEffort).result,
+        Ok(vec![Ok(()), Err(NftsError::AlreadyExists)])
+    );
+    assert_eq!(mock.owner_of(0, 2), None);
+
+    let items = vec![(0, 1, accounts.bob), (0, 2, accounts.bob)];
+    assert_eq!(
+        NftsExtension::dry_run_batch_transfer(items.clone(), BatchModeExt::BestEffort).result,
+        Ok(vec![Ok(()), Err(NftsError::UnknownItem)])
+    );
+    assert_eq!(
+        NftsExtension::dry_run_batch_transfer(items, BatchModeExt::Atomic).result,
+        Err(NftsError::UnknownItem)
+    );
+    assert_eq!(mock.owner_of(0, 1), Some(accounts.alice));
+}
+
+#[ink::test]
+fn mock_applies_the_call_filter() {
+    let (mock, accounts) = setup();
+    let bob = accounts.bob;
+    mock.set_call_filter(move |contract, function| {
+        *contract != bob || function == ids::function::GET_COLLECTION
+    });
+    assert_eq!(NftsExtension::create(create_input(accounts.alice)), Ok(0));
+
+    mock.set_caller(bob);
+    mock.set_balance(bob, 1_000);
+    assert_eq!(
+        NftsExtension::create(create_input(bob)),
+        Err(NftsError::NoPermission)
+    );
+    assert_eq!(
+        NftsExtension::dry_run_create(create_input(bob)).result,
+        Err(NftsError::NoPermission)
+    );
+    assert!(NftsExtension::get_collection(0).is_some());
+}
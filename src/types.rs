@@ -1,6 +1,64 @@
 use super::*;
-use nfts_extension_types::{CollectionDetailsExt, CreateInput};
+use nfts_extension_types::{CollectionDetailsExt, CreateInput, NftsConstantsExt, Page};
 
-pub type DefaultCollectionConfigExt = CollectionConfigExt<Balance, BlockNumber, CollectionId>;
-pub type DefaultCreateInput = CreateInput<AccountId, Balance, BlockNumber, CollectionId>;
-pub type DefaultCollectionDetailsExt = CollectionDetailsExt<AccountId, Balance>;
+pub type CollectionConfigExtFor<E> = CollectionConfigExt<
+    <E as Environment>::Balance,
+    <E as Environment>::BlockNumber,
+    <E as NftsEnvironment>::CollectionId,
+>;
+pub type CreateInputFor<E> = CreateInput<
+    <E as Environment>::AccountId,
+    <E as Environment>::Balance,
+    <E as Environment>::BlockNumber,
+    <E as NftsEnvironment>::CollectionId,
+>;
+pub type CollectionDetailsExtFor<E> =
+    CollectionDetailsExt<<E as Environment>::AccountId, <E as Environment>::Balance>;
+pub type OwnedItemsPageFor<E> = Page<
+    (
+        <E as NftsEnvironment>::CollectionId,
+        <E as NftsEnvironment>::ItemId,
+    ),
+    (
+        <E as NftsEnvironment>::CollectionId,
+        <E as NftsEnvironment>::ItemId,
+    ),
+>;
+pub type CollectionsPageFor<E> =
+    Page<<E as NftsEnvironment>::CollectionId, <E as NftsEnvironment>::CollectionId>;
+pub type NftsConstantsExtFor<E> =
+    NftsConstantsExt<<E as Environment>::Balance, <E as Environment>::BlockNumber>;
+/// Entry of `batch_mint`: the item, its owner and the mint witness.
+pub type BatchMintEntryFor<E> = (
+    <E as NftsEnvironment>::ItemId,
+    <E as Environment>::AccountId,
+    Option<MintWitnessExt<<E as NftsEnvironment>::ItemId>>,
+);
+
+pub type DefaultCollectionConfigExt = CollectionConfigExtFor<DefaultEnvironment>;
+pub type DefaultCreateInput = CreateInputFor<DefaultEnvironment>;
+pub type DefaultCollectionDetailsExt = CollectionDetailsExtFor<DefaultEnvironment>;
+pub type OwnedItemsPage = OwnedItemsPageFor<DefaultEnvironment>;
+pub type CollectionsPage = CollectionsPageFor<DefaultEnvironment>;
+pub type DefaultNftsConstantsExt = NftsConstantsExtFor<DefaultEnvironment>;
+pub type BatchMintEntry = BatchMintEntryFor<DefaultEnvironment>;
+
+/// Outcome of a dry run, see the `dry_run_*` methods of `NftsExtension`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct DryRun<T = ()> {
+    /// What the function would have returned.
+    pub result: Result<T, NftsError>,
+    /// Weight the function consumed.
+    pub weight: WeightExt,
+}
+
+impl<T> DryRun<T> {
+    /// Map the value the function would have returned, keeping the weight.
+    pub(crate) fn and_then<U>(self, f: impl FnOnce(T) -> Result<U, NftsError>) -> DryRun<U> {
+        DryRun {
+            result: self.result.and_then(f),
+            weight: self.weight,
+        }
+    }
+}
@@ -2,9 +2,11 @@
 
 #[ink::contract]
 mod test_contracts {
+    use ink::prelude::vec::Vec;
     use nfts_extension::errors::NftsError;
     use nfts_extension::types::{
-        DefaultCollectionConfigExt, DefaultCollectionDetailsExt, DefaultCreateInput,
+        CollectionsPage, DefaultCollectionConfigExt, DefaultCollectionDetailsExt,
+        DefaultCreateInput, DefaultNftsConstantsExt, DryRun, OwnedItemsPage,
     };
     use nfts_extension::*;
 
@@ -35,20 +37,223 @@ mod test_contracts {
         pub fn get_deposit_per_bytet(&mut self) -> Balance {
             NftsExtension::get_deposit_per_byte()
         }
+        #[ink(message)]
+        pub fn get_nfts_constants(&mut self) -> Result<DefaultNftsConstantsExt, NftsError> {
+            NftsExtension::get_nfts_constants()
+        }
+        #[ink(message)]
+        pub fn get_capabilities(&mut self) -> Result<CapabilitiesExt, NftsError> {
+            NftsExtension::get_capabilities()
+        }
 
         // Chain state query
         #[ink(message)]
         pub fn get_collection(&mut self, id: CollectionId) -> Option<DefaultCollectionDetailsExt> {
             NftsExtension::get_collection(id)
         }
+        #[ink(message)]
+        pub fn get_owned_items(
+            &mut self,
+            account: AccountId,
+            collection: Option<CollectionId>,
+            cursor: Option<(CollectionId, ItemId)>,
+            limit: u32,
+        ) -> Result<OwnedItemsPage, NftsError> {
+            NftsExtension::get_owned_items(account, collection, cursor, limit)
+        }
+        #[ink(message)]
+        pub fn get_collections(
+            &mut self,
+            cursor: Option<CollectionId>,
+            limit: u32,
+        ) -> Result<CollectionsPage, NftsError> {
+            NftsExtension::get_collections(cursor, limit)
+        }
+        #[ink(message)]
+        pub fn get_owned_collections(
+            &mut self,
+            owner: AccountId,
+            cursor: Option<CollectionId>,
+            limit: u32,
+        ) -> Result<CollectionsPage, NftsError> {
+            NftsExtension::get_owned_collections(owner, cursor, limit)
+        }
+        #[ink(message)]
+        pub fn get_collection_config(
+            &mut self,
+            collection: CollectionId,
+        ) -> Result<Option<DefaultCollectionConfigExt>, NftsError> {
+            NftsExtension::get_collection_config(collection)
+        }
+        #[ink(message)]
+        pub fn get_item_config(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+        ) -> Result<Option<ItemSettingsExt>, NftsError> {
+            NftsExtension::get_item_config(collection, item)
+        }
+        #[ink(message)]
+        pub fn get_item_owner(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+        ) -> Result<Option<AccountId>, NftsError> {
+            NftsExtension::get_item_owner(collection, item)
+        }
+        #[ink(message)]
+        pub fn get_balance(
+            &mut self,
+            collection: CollectionId,
+            owner: AccountId,
+        ) -> Result<u32, NftsError> {
+            NftsExtension::get_balance(collection, owner)
+        }
+        #[ink(message)]
+        pub fn get_approval(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+            delegate: AccountId,
+        ) -> Result<Option<Option<BlockNumber>>, NftsError> {
+            NftsExtension::get_approval(collection, item, delegate)
+        }
+        #[ink(message)]
+        pub fn get_attribute(
+            &mut self,
+            collection: CollectionId,
+            maybe_item: Option<ItemId>,
+            namespace: AttributeNamespaceExt<AccountId>,
+            key: Vec<u8>,
+        ) -> Result<Option<Vec<u8>>, NftsError> {
+            NftsExtension::get_attribute(collection, maybe_item, namespace, key)
+        }
 
         #[ink(message, payable)]
         pub fn create(
             &mut self,
             admin: AccountId,
             config: DefaultCollectionConfigExt, // config: DefaultCollectionConfigExt,
+        ) -> Result<CollectionId, NftsError> {
+            NftsExtension::create(DefaultCreateInput { admin, config })
+        }
+
+        #[ink(message)]
+        pub fn dry_run_create(
+            &mut self,
+            admin: AccountId,
+            config: DefaultCollectionConfigExt,
+        ) -> DryRun<CollectionId> {
+            NftsExtension::dry_run_create(DefaultCreateInput { admin, config })
+        }
+
+        #[ink(message)]
+        pub fn redeposit(
+            &mut self,
+            collection: CollectionId,
+            items: Vec<ItemId>,
+        ) -> Result<(), NftsError> {
+            NftsExtension::redeposit(collection, items)?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn batch_mint(
+            &mut self,
+            collection: CollectionId,
+            items: Vec<(ItemId, AccountId, Option<MintWitnessExt<ItemId>>)>,
+            mode: BatchModeExt,
+        ) -> Result<Vec<Result<(), NftsError>>, NftsError> {
+            NftsExtension::batch_mint(collection, items, mode)
+        }
+        #[ink(message)]
+        pub fn batch_transfer(
+            &mut self,
+            items: Vec<(CollectionId, ItemId, AccountId)>,
+            mode: BatchModeExt,
+        ) -> Result<Vec<Result<(), NftsError>>, NftsError> {
+            NftsExtension::batch_transfer(items, mode)
+        }
+        #[ink(message)]
+        pub fn transfer(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+            dest: AccountId,
+        ) -> Result<(), NftsError> {
+            NftsExtension::transfer(collection, item, dest)
+        }
+        #[ink(message)]
+        pub fn approve_transfer(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+            delegate: AccountId,
+            maybe_deadline: Option<BlockNumber>,
+        ) -> Result<(), NftsError> {
+            NftsExtension::approve_transfer(collection, item, delegate, maybe_deadline)
+        }
+        #[ink(message)]
+        pub fn cancel_approval(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+            delegate: AccountId,
+        ) -> Result<(), NftsError> {
+            NftsExtension::cancel_approval(collection, item, delegate)
+        }
+
+        // Privileged extrinsics
+        #[ink(message)]
+        pub fn force_create(
+            &mut self,
+            owner: AccountId,
+            config: DefaultCollectionConfigExt,
+        ) -> Result<CollectionId, NftsError> {
+            NftsExtension::force_create(owner, config)
+        }
+        #[ink(message)]
+        pub fn force_mint(
+            &mut self,
+            collection: CollectionId,
+            item: ItemId,
+            mint_to: AccountId,
+            item_settings: ItemSettingsExt,
+        ) -> Result<(), NftsError> {
+            NftsExtension::force_mint(collection, item, mint_to, item_settings)?;
+            Ok(())
+        }
+        #[ink(message)]
+        pub fn force_collection_owner(
+            &mut self,
+            collection: CollectionId,
+            owner: AccountId,
+        ) -> Result<(), NftsError> {
+            NftsExtension::force_collection_owner(collection, owner)?;
+            Ok(())
+        }
+        #[ink(message)]
+        pub fn force_collection_config(
+            &mut self,
+            collection: CollectionId,
+            config: DefaultCollectionConfigExt,
+        ) -> Result<(), NftsError> {
+            NftsExtension::force_collection_config(collection, config)?;
+            Ok(())
+        }
+        #[ink(message)]
+        pub fn force_set_attribute(
+            &mut self,
+            set_as: Option<AccountId>,
+            collection: CollectionId,
+            maybe_item: Option<ItemId>,
+            namespace: AttributeNamespaceExt<AccountId>,
+            key: Vec<u8>,
+            value: Vec<u8>,
         ) -> Result<(), NftsError> {
-            NftsExtension::create(DefaultCreateInput { admin, config })?;
+            NftsExtension::force_set_attribute(
+                set_as, collection, maybe_item, namespace, key, value,
+            )?;
             Ok(())
         }
     }
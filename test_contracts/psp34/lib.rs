@@ -0,0 +1,130 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// PSP34 collection over a collection of the nfts pallet, answering the messages of the standard
+/// with `Psp34Collection`.
+#[ink::contract]
+mod psp34_collection {
+    use ink::prelude::vec::Vec;
+    use nfts_extension::psp34::{Id, PSP34Error, PSP34Metadata, Psp34Collection, PSP34};
+    use nfts_extension::CollectionId;
+
+    #[ink(storage)]
+    pub struct Collection {
+        nfts: Psp34Collection,
+    }
+
+    impl Collection {
+        #[ink(constructor)]
+        pub fn new(collection: CollectionId) -> Self {
+            Self {
+                nfts: Psp34Collection::new(collection),
+            }
+        }
+    }
+
+    impl PSP34 for Collection {
+        #[ink(message)]
+        fn collection_id(&self) -> Id {
+            self.nfts.collection_id()
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u32 {
+            self.nfts.balance_of(owner)
+        }
+
+        #[ink(message)]
+        fn owner_of(&self, id: Id) -> Option<AccountId> {
+            self.nfts.owner_of(id)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
+            self.nfts.allowance(owner, operator, id)
+        }
+
+        #[ink(message)]
+        fn approve(
+            &mut self,
+            operator: AccountId,
+            id: Option<Id>,
+            approved: bool,
+        ) -> Result<(), PSP34Error> {
+            let caller = self.env().caller();
+            self.nfts.approve(caller, operator, id, approved)
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+            let caller = self.env().caller();
+            self.nfts.transfer(caller, to, id, data)
+        }
+
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.nfts.total_supply()
+        }
+    }
+
+    impl PSP34Metadata for Collection {
+        #[ink(message)]
+        fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
+            self.nfts.get_attribute(id, key)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test::{callee, default_accounts, set_caller};
+        use ink::env::DefaultEnvironment;
+        use nfts_extension::mock::NftsMock;
+        use nfts_extension::types::DefaultCreateInput;
+        use nfts_extension::{BatchModeExt, CollectionConfigExt, NftsExtension};
+
+        #[ink::test]
+        fn transfers_for_the_approved_operators() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let contract = callee::<DefaultEnvironment>();
+            let mock = NftsMock::new();
+            mock.set_caller(contract);
+            mock.set_balance(contract, 1_000_000);
+            mock.register();
+            let input = DefaultCreateInput {
+                admin: contract,
+                config: CollectionConfigExt::default(),
+            };
+            assert_eq!(NftsExtension::create(input), Ok(0));
+            let items = vec![(1, accounts.bob, None)];
+            assert!(NftsExtension::batch_mint(0, items, BatchModeExt::Atomic).is_ok());
+
+            let mut collection = Collection::new(0);
+            assert_eq!(collection.collection_id(), Id::U32(0));
+            assert_eq!(collection.total_supply(), 1);
+            assert_eq!(collection.balance_of(accounts.bob), 1);
+
+            // The holder approves the contract on the pallet and an operator on the contract.
+            mock.set_caller(accounts.bob);
+            assert_eq!(
+                NftsExtension::approve_transfer(0, 1, contract, None),
+                Ok(())
+            );
+            mock.set_caller(contract);
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(collection.approve(accounts.charlie, None, true), Ok(()));
+            assert!(collection.allowance(accounts.bob, accounts.charlie, Some(Id::U32(1))));
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                collection.transfer(accounts.django, Id::U32(1), Vec::new()),
+                Err(PSP34Error::NotApproved)
+            );
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                collection.transfer(accounts.django, Id::U32(1), Vec::new()),
+                Ok(())
+            );
+            assert_eq!(collection.owner_of(Id::U32(1)), Some(accounts.django));
+        }
+    }
+}
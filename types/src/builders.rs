@@ -0,0 +1,290 @@
+//! Builders, presets and defaults of the collection and item configurations.
+//!
+//! The defaults match the ones of the nfts pallet: every setting is enabled, anything can be
+//! changed later on, and only the issuer can mint.
+//!
+//! ```
+//! use nfts_extension_types::{CollectionConfigExt, ItemSettingsExt, MintTypeExt};
+//!
+//! let config = CollectionConfigExt::<u128, u32, u32>::builder()
+//!     .max_supply(1_000)
+//!     .mint_type(MintTypeExt::Public)
+//!     .default_item_settings(ItemSettingsExt::soulbound())
+//!     .build();
+//! assert!(!config.mint_settings.default_item_settings.transferable);
+//! ```
+
+use crate::{
+    CollectionConfigExt, CollectionSettingsExt, ItemSettingsExt, MintSettingsExt, MintTypeExt,
+};
+
+impl Default for ItemSettingsExt {
+    fn default() -> Self {
+        Self {
+            transferable: true,
+            unlocked_metadata: true,
+            unlocked_attributes: true,
+        }
+    }
+}
+
+impl ItemSettingsExt {
+    pub fn builder() -> ItemSettingsBuilder {
+        ItemSettingsBuilder(Self::default())
+    }
+
+    /// Items bound to their owner: they can't be transferred.
+    pub fn soulbound() -> Self {
+        Self::builder().transferable(false).build()
+    }
+
+    /// Items whose metadata and attributes can't be modified.
+    pub fn locked() -> Self {
+        Self::builder()
+            .unlocked_metadata(false)
+            .unlocked_attributes(false)
+            .build()
+    }
+}
+
+/// Builder of `ItemSettingsExt`, starting from the default settings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ItemSettingsBuilder(ItemSettingsExt);
+
+impl ItemSettingsBuilder {
+    pub fn transferable(mut self, transferable: bool) -> Self {
+        self.0.transferable = transferable;
+        self
+    }
+
+    pub fn unlocked_metadata(mut self, unlocked_metadata: bool) -> Self {
+        self.0.unlocked_metadata = unlocked_metadata;
+        self
+    }
+
+    pub fn unlocked_attributes(mut self, unlocked_attributes: bool) -> Self {
+        self.0.unlocked_attributes = unlocked_attributes;
+        self
+    }
+
+    pub fn build(self) -> ItemSettingsExt {
+        self.0
+    }
+}
+
+impl Default for CollectionSettingsExt {
+    fn default() -> Self {
+        Self {
+            transferable_items: true,
+            unlocked_metadata: true,
+            unlocked_attributes: true,
+            unlocked_max_supply: true,
+            deposit_required: true,
+        }
+    }
+}
+
+impl CollectionSettingsExt {
+    pub fn builder() -> CollectionSettingsBuilder {
+        CollectionSettingsBuilder(Self::default())
+    }
+
+    /// A collection whose metadata, attributes and max supply can't be modified.
+    pub fn locked() -> Self {
+        Self::builder()
+            .unlocked_metadata(false)
+            .unlocked_attributes(false)
+            .unlocked_max_supply(false)
+            .build()
+    }
+}
+
+/// Builder of `CollectionSettingsExt`, starting from the default settings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CollectionSettingsBuilder(CollectionSettingsExt);
+
+impl CollectionSettingsBuilder {
+    pub fn transferable_items(mut self, transferable_items: bool) -> Self {
+        self.0.transferable_items = transferable_items;
+        self
+    }
+
+    pub fn unlocked_metadata(mut self, unlocked_metadata: bool) -> Self {
+        self.0.unlocked_metadata = unlocked_metadata;
+        self
+    }
+
+    pub fn unlocked_attributes(mut self, unlocked_attributes: bool) -> Self {
+        self.0.unlocked_attributes = unlocked_attributes;
+        self
+    }
+
+    pub fn unlocked_max_supply(mut self, unlocked_max_supply: bool) -> Self {
+        self.0.unlocked_max_supply = unlocked_max_supply;
+        self
+    }
+
+    pub fn deposit_required(mut self, deposit_required: bool) -> Self {
+        self.0.deposit_required = deposit_required;
+        self
+    }
+
+    pub fn build(self) -> CollectionSettingsExt {
+        self.0
+    }
+}
+
+impl<Price, BlockNumber, CollectionId> Default
+    for MintSettingsExt<Price, BlockNumber, CollectionId>
+{
+    fn default() -> Self {
+        Self {
+            mint_type: MintTypeExt::Issuer,
+            price: None,
+            start_block: None,
+            end_block: None,
+            default_item_settings: ItemSettingsExt::default(),
+        }
+    }
+}
+
+impl<Price, BlockNumber, CollectionId> MintSettingsExt<Price, BlockNumber, CollectionId> {
+    pub fn builder() -> MintSettingsBuilder<Price, BlockNumber, CollectionId> {
+        MintSettingsBuilder(Self::default())
+    }
+}
+
+/// Builder of `MintSettingsExt`, starting from the default settings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MintSettingsBuilder<Price, BlockNumber, CollectionId>(
+    MintSettingsExt<Price, BlockNumber, CollectionId>,
+);
+
+impl<Price, BlockNumber, CollectionId> MintSettingsBuilder<Price, BlockNumber, CollectionId> {
+    pub fn mint_type(mut self, mint_type: MintTypeExt<CollectionId>) -> Self {
+        self.0.mint_type = mint_type;
+        self
+    }
+
+    pub fn price(mut self, price: Price) -> Self {
+        self.0.price = Some(price);
+        self
+    }
+
+    pub fn start_block(mut self, start_block: BlockNumber) -> Self {
+        self.0.start_block = Some(start_block);
+        self
+    }
+
+    pub fn end_block(mut self, end_block: BlockNumber) -> Self {
+        self.0.end_block = Some(end_block);
+        self
+    }
+
+    pub fn default_item_settings(mut self, default_item_settings: ItemSettingsExt) -> Self {
+        self.0.default_item_settings = default_item_settings;
+        self
+    }
+
+    pub fn build(self) -> MintSettingsExt<Price, BlockNumber, CollectionId> {
+        self.0
+    }
+}
+
+impl<Price, BlockNumber, CollectionId> Default
+    for CollectionConfigExt<Price, BlockNumber, CollectionId>
+{
+    fn default() -> Self {
+        Self {
+            setting: CollectionSettingsExt::default(),
+            max_supply: None,
+            mint_settings: MintSettingsExt::default(),
+        }
+    }
+}
+
+impl<Price, BlockNumber, CollectionId> CollectionConfigExt<Price, BlockNumber, CollectionId> {
+    pub fn builder() -> CollectionConfigBuilder<Price, BlockNumber, CollectionId> {
+        CollectionConfigBuilder(Self::default())
+    }
+
+    /// Anyone can mint items.
+    pub fn public_mint() -> Self {
+        Self::builder().mint_type(MintTypeExt::Public).build()
+    }
+
+    /// Only the issuer can mint items, the default.
+    pub fn issuer_only() -> Self {
+        Self::default()
+    }
+
+    /// The items, minted by the issuer, can't be transferred by their owners.
+    pub fn soulbound() -> Self {
+        Self::builder()
+            .settings(
+                CollectionSettingsExt::builder()
+                    .transferable_items(false)
+                    .build(),
+            )
+            .default_item_settings(ItemSettingsExt::soulbound())
+            .build()
+    }
+
+    /// Nothing of the collection and of its items can be modified: metadata, attributes and max
+    /// supply. The items can still be transferred.
+    pub fn fully_locked() -> Self {
+        Self::builder()
+            .settings(CollectionSettingsExt::locked())
+            .default_item_settings(ItemSettingsExt::locked())
+            .build()
+    }
+
+    /// Only the holders of an item of `collection` can mint items, once per item they hold.
+    pub fn holder_gated(collection: CollectionId) -> Self {
+        Self::builder()
+            .mint_type(MintTypeExt::HolderOf(collection))
+            .build()
+    }
+}
+
+/// Builder of `CollectionConfigExt`, starting from the default configuration.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CollectionConfigBuilder<Price, BlockNumber, CollectionId>(
+    CollectionConfigExt<Price, BlockNumber, CollectionId>,
+);
+
+impl<Price, BlockNumber, CollectionId> CollectionConfigBuilder<Price, BlockNumber, CollectionId> {
+    pub fn settings(mut self, settings: CollectionSettingsExt) -> Self {
+        self.0.setting = settings;
+        self
+    }
+
+    pub fn max_supply(mut self, max_supply: u32) -> Self {
+        self.0.max_supply = Some(max_supply);
+        self
+    }
+
+    pub fn mint_settings(
+        mut self,
+        mint_settings: MintSettingsExt<Price, BlockNumber, CollectionId>,
+    ) -> Self {
+        self.0.mint_settings = mint_settings;
+        self
+    }
+
+    /// Shortcut to set the mint type of the mint settings.
+    pub fn mint_type(mut self, mint_type: MintTypeExt<CollectionId>) -> Self {
+        self.0.mint_settings.mint_type = mint_type;
+        self
+    }
+
+    /// Shortcut to set the default item settings of the mint settings.
+    pub fn default_item_settings(mut self, default_item_settings: ItemSettingsExt) -> Self {
+        self.0.mint_settings.default_item_settings = default_item_settings;
+        self
+    }
+
+    pub fn build(self) -> CollectionConfigExt<Price, BlockNumber, CollectionId> {
+        self.0
+    }
+}
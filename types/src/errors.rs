@@ -0,0 +1,353 @@
+//! Errors of the extension, shared by the runtime, the contracts and the clients.
+//!
+//! The runtime returns the status code of an error, the discriminant of its `NftsError` variant,
+//! and [`SUCCESS`] when the function succeeded.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::fmt;
+
+/// Status code of a successful call.
+pub const SUCCESS: u32 = 0;
+
+/// Status codes of the extension other than success. The variants have the discriminants and so
+/// the SCALE encoding of the status codes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum NftsError {
+    /// The signing account has no permission to do the operation.
+    NoPermission = 2,
+    /// The given item ID is unknown.
+    UnknownCollection = 3,
+    /// The item ID has already been used for an item.
+    AlreadyExists = 4,
+    /// The approval had a deadline that expired, so the approval isn't valid anymore.
+    ApprovalExpired = 5,
+    /// The owner turned out to be different to what was expected.
+    WrongOwner = 6,
+    /// The witness data given does not match the current state of the chain.
+    BadWitness = 7,
+    /// Collection ID is already taken.
+    CollectionIdInUse = 8,
+    /// Items within that collection are non-transferable.
+    ItemsNonTransferable = 9,
+    /// The provided account is not a delegate.
+    NotDelegate = 10,
+    /// The delegate turned out to be different to what was expected.
+    WrongDelegate = 11,
+    /// No approval exists that would allow the transfer.
+    Unapproved = 12,
+    /// The named owner has not signed ownership acceptance of the collection.
+    Unaccepted = 13,
+    /// The item is locked (non-transferable).
+    ItemLocked = 14,
+    /// Item's attributes are locked.
+    LockedItemAttributes = 15,
+    /// Collection's attributes are locked.
+    LockedCollectionAttributes = 16,
+    /// Item's metadata is locked.
+    LockedItemMetadata = 17,
+    /// Collection's metadata is locked.
+    LockedCollectionMetadata = 18,
+    /// All items have been minted.
+    MaxSupplyReached = 19,
+    /// The max supply is locked and can't be changed.
+    MaxSupplyLocked = 20,
+    /// The provided max supply is less than the number of items a collection already has.
+    MaxSupplyTooSmall = 21,
+    /// The given item ID is unknown.
+    UnknownItem = 22,
+    /// Swap doesn't exist.
+    UnknownSwap = 23,
+    /// The given item has no metadata set.
+    MetadataNotFound = 24,
+    /// The provided attribute can't be found.
+    AttributeNotFound = 25,
+    /// Item is not for sale.
+    NotForSale = 26,
+    /// The provided bid is too low.
+    BidTooLow = 27,
+    /// The item has reached its approval limit.
+    ReachedApprovalLimit = 28,
+    /// The deadline has already expired.
+    DeadlineExpired = 29,
+    /// The duration provided should be less than or equal to `MaxDeadlineDuration`.
+    WrongDuration = 30,
+    /// The method is disabled by system settings.
+    MethodDisabled = 31,
+    /// The provided setting can't be set.
+    WrongSetting = 32,
+    /// Item's config already exists and should be equal to the provided one.
+    InconsistentItemConfig = 33,
+    /// Config for a collection or an item can't be found.
+    NoConfig = 34,
+    /// Some roles were not cleared.
+    RolesNotCleared = 35,
+    /// Mint has not started yet.
+    MintNotStarted = 36,
+    /// Mint has already ended.
+    MintEnded = 37,
+    /// The provided Item was already used for claiming.
+    AlreadyClaimed = 38,
+    /// The provided data is incorrect.
+    IncorrectData = 39,
+    /// The extrinsic was sent by the wrong origin.
+    WrongOrigin = 40,
+    /// The provided signature is incorrect.
+    WrongSignature = 41,
+    /// The provided metadata might be too long.
+    IncorrectMetadata = 42,
+    /// Can't set more attributes per one call.
+    MaxAttributesLimitReached = 43,
+    /// The provided namespace isn't supported in this call.
+    WrongNamespace = 44,
+    /// Can't delete non-empty collections.
+    CollectionNotEmpty = 45,
+    /// The call input is longer than the pallet limits allow.
+    InputTooLarge = 46,
+    /// The function id or its version isn't served by the extension of the runtime.
+    UnsupportedFunction = 47,
+    /// The origin of the call can't be the caller of the contract.
+    OriginCannotBeCaller = 48,
+    /// The call input is inconsistent, e.g. a cursor outside of the queried collection.
+    InvalidInput = 49,
+    /// Unknown error, or a status code unknown to this release.
+    UnknownError = 99,
+}
+
+impl NftsError {
+    /// Error of a status code, `Ok` for the success status `0`.
+    pub fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            2 => Err(Self::NoPermission),
+            3 => Err(Self::UnknownCollection),
+            4 => Err(Self::AlreadyExists),
+            5 => Err(Self::ApprovalExpired),
+            6 => Err(Self::WrongOwner),
+            7 => Err(Self::BadWitness),
+            8 => Err(Self::CollectionIdInUse),
+            9 => Err(Self::ItemsNonTransferable),
+            10 => Err(Self::NotDelegate),
+            11 => Err(Self::WrongDelegate),
+            12 => Err(Self::Unapproved),
+            13 => Err(Self::Unaccepted),
+            14 => Err(Self::ItemLocked),
+            15 => Err(Self::LockedItemAttributes),
+            16 => Err(Self::LockedCollectionAttributes),
+            17 => Err(Self::LockedItemMetadata),
+            18 => Err(Self::LockedCollectionMetadata),
+            19 => Err(Self::MaxSupplyReached),
+            20 => Err(Self::MaxSupplyLocked),
+            21 => Err(Self::MaxSupplyTooSmall),
+            22 => Err(Self::UnknownItem),
+            23 => Err(Self::UnknownSwap),
+            24 => Err(Self::MetadataNotFound),
+            25 => Err(Self::AttributeNotFound),
+            26 => Err(Self::NotForSale),
+            27 => Err(Self::BidTooLow),
+            28 => Err(Self::ReachedApprovalLimit),
+            29 => Err(Self::DeadlineExpired),
+            30 => Err(Self::WrongDuration),
+            31 => Err(Self::MethodDisabled),
+            32 => Err(Self::WrongSetting),
+            33 => Err(Self::InconsistentItemConfig),
+            34 => Err(Self::NoConfig),
+            35 => Err(Self::RolesNotCleared),
+            36 => Err(Self::MintNotStarted),
+            37 => Err(Self::MintEnded),
+            38 => Err(Self::AlreadyClaimed),
+            39 => Err(Self::IncorrectData),
+            40 => Err(Self::WrongOrigin),
+            41 => Err(Self::WrongSignature),
+            42 => Err(Self::IncorrectMetadata),
+            43 => Err(Self::MaxAttributesLimitReached),
+            44 => Err(Self::WrongNamespace),
+            45 => Err(Self::CollectionNotEmpty),
+            46 => Err(Self::InputTooLarge),
+            47 => Err(Self::UnsupportedFunction),
+            48 => Err(Self::OriginCannotBeCaller),
+            49 => Err(Self::InvalidInput),
+            _ => Err(Self::UnknownError),
+        }
+    }
+
+    /// Status code of the error as returned by the runtime, the inverse of `from_status_code`.
+    pub fn status_code(self) -> u32 {
+        self as u32
+    }
+
+    /// Group of the error, to handle the failures of a kind together.
+    pub fn category(self) -> NftsErrorCategory {
+        match self {
+            Self::NoPermission
+            | Self::WrongOwner
+            | Self::NotDelegate
+            | Self::WrongDelegate
+            | Self::Unapproved
+            | Self::Unaccepted
+            | Self::WrongOrigin
+            | Self::WrongSignature
+            | Self::OriginCannotBeCaller => NftsErrorCategory::Permission,
+            Self::UnknownCollection
+            | Self::UnknownItem
+            | Self::UnknownSwap
+            | Self::MetadataNotFound
+            | Self::AttributeNotFound
+            | Self::NoConfig => NftsErrorCategory::NotFound,
+            Self::ItemsNonTransferable
+            | Self::ItemLocked
+            | Self::LockedItemAttributes
+            | Self::LockedCollectionAttributes
+            | Self::LockedItemMetadata
+            | Self::LockedCollectionMetadata
+            | Self::MaxSupplyLocked
+            | Self::MethodDisabled => NftsErrorCategory::Locked,
+            Self::MaxSupplyReached
+            | Self::MaxSupplyTooSmall
+            | Self::ReachedApprovalLimit
+            | Self::WrongDuration
+            | Self::MaxAttributesLimitReached
+            | Self::InputTooLarge => NftsErrorCategory::Limit,
+            Self::ApprovalExpired
+            | Self::DeadlineExpired
+            | Self::MintNotStarted
+            | Self::MintEnded => NftsErrorCategory::Timing,
+            Self::AlreadyExists
+            | Self::BadWitness
+            | Self::CollectionIdInUse
+            | Self::NotForSale
+            | Self::BidTooLow
+            | Self::WrongSetting
+            | Self::InconsistentItemConfig
+            | Self::RolesNotCleared
+            | Self::AlreadyClaimed
+            | Self::IncorrectData
+            | Self::IncorrectMetadata
+            | Self::WrongNamespace
+            | Self::CollectionNotEmpty
+            | Self::UnknownError
+            | Self::UnsupportedFunction
+            | Self::InvalidInput => NftsErrorCategory::Other,
+        }
+    }
+
+    /// The caller isn't allowed to do the operation, e.g. `NoPermission` or `WrongOwner`.
+    pub fn is_permission(self) -> bool {
+        self.category() == NftsErrorCategory::Permission
+    }
+
+    /// The collection, item or data doesn't exist, e.g. `UnknownItem` or `UnknownCollection`.
+    pub fn is_not_found(self) -> bool {
+        self.category() == NftsErrorCategory::NotFound
+    }
+
+    /// The collection or item is locked by its settings, e.g. `ItemLocked`.
+    pub fn is_locked(self) -> bool {
+        self.category() == NftsErrorCategory::Locked
+    }
+
+    /// A limit of the pallet or of the collection is reached, e.g. `MaxSupplyReached`.
+    pub fn is_limit(self) -> bool {
+        self.category() == NftsErrorCategory::Limit
+    }
+
+    /// The operation is too early or too late, e.g. `MintNotStarted` or `DeadlineExpired`.
+    pub fn is_timing(self) -> bool {
+        self.category() == NftsErrorCategory::Timing
+    }
+}
+
+impl fmt::Display for NftsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::NoPermission => "the caller has no permission to do the operation",
+            Self::UnknownCollection => "the collection is unknown",
+            Self::AlreadyExists => "the item id has already been used for an item",
+            Self::ApprovalExpired => "the approval has expired",
+            Self::WrongOwner => "the owner is different to what was expected",
+            Self::BadWitness => "the witness data doesn't match the state of the chain",
+            Self::CollectionIdInUse => "the collection id is already taken",
+            Self::ItemsNonTransferable => "the items of the collection are non-transferable",
+            Self::NotDelegate => "the account is not a delegate",
+            Self::WrongDelegate => "the delegate is different to what was expected",
+            Self::Unapproved => "no approval allows the transfer",
+            Self::Unaccepted => "the new owner hasn't accepted the ownership of the collection",
+            Self::ItemLocked => "the item is locked",
+            Self::LockedItemAttributes => "the attributes of the item are locked",
+            Self::LockedCollectionAttributes => "the attributes of the collection are locked",
+            Self::LockedItemMetadata => "the metadata of the item is locked",
+            Self::LockedCollectionMetadata => "the metadata of the collection is locked",
+            Self::MaxSupplyReached => "all the items of the collection have been minted",
+            Self::MaxSupplyLocked => "the max supply is locked",
+            Self::MaxSupplyTooSmall => "the max supply is less than the number of items",
+            Self::UnknownItem => "the item is unknown",
+            Self::UnknownSwap => "the swap doesn't exist",
+            Self::MetadataNotFound => "the item has no metadata",
+            Self::AttributeNotFound => "the attribute can't be found",
+            Self::NotForSale => "the item is not for sale",
+            Self::BidTooLow => "the bid is too low",
+            Self::ReachedApprovalLimit => "the item has reached its approval limit",
+            Self::DeadlineExpired => "the deadline has expired",
+            Self::WrongDuration => "the duration is longer than the max deadline duration",
+            Self::MethodDisabled => "the method is disabled by the settings",
+            Self::WrongSetting => "the setting can't be set",
+            Self::InconsistentItemConfig => "the item config differs from the existing one",
+            Self::NoConfig => "the config of the collection or item can't be found",
+            Self::RolesNotCleared => "some roles were not cleared",
+            Self::MintNotStarted => "the mint has not started yet",
+            Self::MintEnded => "the mint has ended",
+            Self::AlreadyClaimed => "the item was already used for claiming",
+            Self::IncorrectData => "the data is incorrect",
+            Self::WrongOrigin => "the call was sent by the wrong origin",
+            Self::WrongSignature => "the signature is incorrect",
+            Self::IncorrectMetadata => "the metadata is incorrect or too long",
+            Self::MaxAttributesLimitReached => "too many attributes are set in one call",
+            Self::WrongNamespace => "the namespace isn't supported by the call",
+            Self::CollectionNotEmpty => "the collection is not empty",
+            Self::OriginCannotBeCaller => "the origin can't be the caller",
+            Self::UnknownError => "unknown error",
+            Self::InputTooLarge => "the input is longer than the pallet limits allow",
+            Self::UnsupportedFunction => "the function isn't supported by the runtime",
+            Self::InvalidInput => "the input is inconsistent",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NftsError {}
+
+/// Groups of the errors of the extension, see [`NftsError::category`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum NftsErrorCategory {
+    /// The caller isn't allowed to do the operation.
+    Permission,
+    /// The collection, item or data doesn't exist.
+    NotFound,
+    /// The collection or item is locked by its settings.
+    Locked,
+    /// A limit of the pallet or of the collection is reached.
+    Limit,
+    /// The operation is too early or too late.
+    Timing,
+    /// Any other error.
+    Other,
+}
+
+/// Status codes of the chain extension methods of ink.
+#[cfg(feature = "ink")]
+impl ink::env::chain_extension::FromStatusCode for NftsError {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        NftsError::from_status_code(status_code)
+    }
+}
+
+/// Required by the chain extension methods of ink, which decode their output beforehand: an
+/// invalid output is a bug of the runtime.
+#[cfg(feature = "ink")]
+impl From<codec::Error> for NftsError {
+    fn from(_: codec::Error) -> Self {
+        panic!("encountered unexpected invalid SCALE encoding")
+    }
+}
@@ -0,0 +1,243 @@
+//! Compact bit flags representations of the settings, features and roles.
+//!
+//! Each type has the same bits and SCALE encoding as its counterpart of the nfts pallet, so a
+//! value converts losslessly between the two. Like the pallet, the settings and features flags
+//! hold what is *disabled*, and the roles flags hold the roles an account has.
+//!
+//! ```
+//! use codec::{Decode, Encode};
+//! use nfts_extension_types::flags::CollectionSettingsFlags;
+//! use nfts_extension_types::CollectionSettingsExt;
+//!
+//! let settings = CollectionSettingsExt::builder()
+//!     .transferable_items(false)
+//!     .build();
+//! let flags = CollectionSettingsFlags::from(settings);
+//! assert_eq!(flags.bits(), CollectionSettingsFlags::TRANSFERABLE_ITEMS);
+//! assert_eq!(flags.encode(), 1u64.encode());
+//! assert_eq!(CollectionSettingsExt::from(flags), settings);
+//!
+//! // Unknown bits don't decode.
+//! assert!(CollectionSettingsFlags::decode(&mut &(1u64 << 5).encode()[..]).is_err());
+//! ```
+
+use crate::{CollectionSettingsExt, ItemSettingsExt, PalletFeaturesExt};
+use codec::{Decode, Encode, Error, Input, MaxEncodedLen};
+
+macro_rules! bit_flags {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident($repr:ty) {
+            $(
+                $(#[$flag_attr:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Encode, MaxEncodedLen)]
+        #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+        pub struct $name($repr);
+
+        impl $name {
+            $(
+                $(#[$flag_attr])*
+                pub const $flag: $repr = $value;
+            )*
+            /// Every known flag.
+            pub const ALL: $repr = 0 $(| $value)*;
+
+            /// The flags of the given bits, `None` if any of them is unknown.
+            pub const fn from_bits(bits: $repr) -> Option<Self> {
+                if bits & !Self::ALL != 0 {
+                    None
+                } else {
+                    Some(Self(bits))
+                }
+            }
+
+            /// The flags of the given bits, the unknown ones are dropped.
+            pub const fn from_bits_truncate(bits: $repr) -> Self {
+                Self(bits & Self::ALL)
+            }
+
+            pub const fn bits(&self) -> $repr {
+                self.0
+            }
+
+            /// Whether all the given flags are set.
+            pub const fn contains(&self, flags: $repr) -> bool {
+                self.0 & flags == flags
+            }
+
+            pub fn insert(&mut self, flags: $repr) {
+                self.0 |= flags & Self::ALL;
+            }
+
+            pub fn remove(&mut self, flags: $repr) {
+                self.0 &= !flags;
+            }
+        }
+
+        /// Rejects the unknown bits, as the nfts pallet does.
+        impl Decode for $name {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                let bits = <$repr>::decode(input)?;
+                Self::from_bits(bits).ok_or_else(|| concat!("Invalid ", stringify!($name)).into())
+            }
+        }
+
+        /// Serialized as its bits.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        /// Rejects the unknown bits, as `Decode`.
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let bits = <$repr as serde::Deserialize>::deserialize(deserializer)?;
+                Self::from_bits(bits).ok_or_else(|| {
+                    serde::de::Error::custom(concat!("Invalid ", stringify!($name)))
+                })
+            }
+        }
+    };
+}
+
+bit_flags! {
+    /// Disabled settings of a collection, as `pallet_nfts::CollectionSettings`.
+    pub struct CollectionSettingsFlags(u64) {
+        const TRANSFERABLE_ITEMS = 1;
+        const UNLOCKED_METADATA = 1 << 1;
+        const UNLOCKED_ATTRIBUTES = 1 << 2;
+        const UNLOCKED_MAX_SUPPLY = 1 << 3;
+        const DEPOSIT_REQUIRED = 1 << 4;
+    }
+}
+
+bit_flags! {
+    /// Disabled settings of an item, as `pallet_nfts::ItemSettings`.
+    pub struct ItemSettingsFlags(u64) {
+        const TRANSFERABLE = 1;
+        const UNLOCKED_METADATA = 1 << 1;
+        const UNLOCKED_ATTRIBUTES = 1 << 2;
+    }
+}
+
+bit_flags! {
+    /// Disabled features of the pallet, as `pallet_nfts::PalletFeatures`.
+    pub struct PalletFeaturesFlags(u64) {
+        const TRADING = 1;
+        const ATTRIBUTES = 1 << 1;
+        const APPROVALS = 1 << 2;
+        const SWAPS = 1 << 3;
+    }
+}
+
+bit_flags! {
+    /// Roles of an account in a collection, as `pallet_nfts::CollectionRoles`.
+    pub struct CollectionRolesFlags(u8) {
+        /// Can mint items.
+        const ISSUER = 1;
+        /// Can freeze items.
+        const FREEZER = 1 << 1;
+        /// Can thaw items, force transfers and burn items from any account.
+        const ADMIN = 1 << 2;
+    }
+}
+
+/// Set `flag` in `flags` when the setting is disabled.
+fn disable_if<Repr>(flags: &mut Repr, enabled: bool, flag: Repr)
+where
+    Repr: core::ops::BitOrAssign,
+{
+    if !enabled {
+        *flags |= flag;
+    }
+}
+
+impl From<CollectionSettingsExt> for CollectionSettingsFlags {
+    fn from(value: CollectionSettingsExt) -> Self {
+        let mut bits = 0;
+        disable_if(
+            &mut bits,
+            value.transferable_items,
+            Self::TRANSFERABLE_ITEMS,
+        );
+        disable_if(&mut bits, value.unlocked_metadata, Self::UNLOCKED_METADATA);
+        disable_if(
+            &mut bits,
+            value.unlocked_attributes,
+            Self::UNLOCKED_ATTRIBUTES,
+        );
+        disable_if(
+            &mut bits,
+            value.unlocked_max_supply,
+            Self::UNLOCKED_MAX_SUPPLY,
+        );
+        disable_if(&mut bits, value.deposit_required, Self::DEPOSIT_REQUIRED);
+        Self(bits)
+    }
+}
+
+impl From<CollectionSettingsFlags> for CollectionSettingsExt {
+    fn from(value: CollectionSettingsFlags) -> Self {
+        Self {
+            transferable_items: !value.contains(CollectionSettingsFlags::TRANSFERABLE_ITEMS),
+            unlocked_metadata: !value.contains(CollectionSettingsFlags::UNLOCKED_METADATA),
+            unlocked_attributes: !value.contains(CollectionSettingsFlags::UNLOCKED_ATTRIBUTES),
+            unlocked_max_supply: !value.contains(CollectionSettingsFlags::UNLOCKED_MAX_SUPPLY),
+            deposit_required: !value.contains(CollectionSettingsFlags::DEPOSIT_REQUIRED),
+        }
+    }
+}
+
+impl From<ItemSettingsExt> for ItemSettingsFlags {
+    fn from(value: ItemSettingsExt) -> Self {
+        let mut bits = 0;
+        disable_if(&mut bits, value.transferable, Self::TRANSFERABLE);
+        disable_if(&mut bits, value.unlocked_metadata, Self::UNLOCKED_METADATA);
+        disable_if(
+            &mut bits,
+            value.unlocked_attributes,
+            Self::UNLOCKED_ATTRIBUTES,
+        );
+        Self(bits)
+    }
+}
+
+impl From<ItemSettingsFlags> for ItemSettingsExt {
+    fn from(value: ItemSettingsFlags) -> Self {
+        Self {
+            transferable: !value.contains(ItemSettingsFlags::TRANSFERABLE),
+            unlocked_metadata: !value.contains(ItemSettingsFlags::UNLOCKED_METADATA),
+            unlocked_attributes: !value.contains(ItemSettingsFlags::UNLOCKED_ATTRIBUTES),
+        }
+    }
+}
+
+impl From<PalletFeaturesExt> for PalletFeaturesFlags {
+    fn from(value: PalletFeaturesExt) -> Self {
+        let mut bits = 0;
+        disable_if(&mut bits, value.trading, Self::TRADING);
+        disable_if(&mut bits, value.attributes, Self::ATTRIBUTES);
+        disable_if(&mut bits, value.approvals, Self::APPROVALS);
+        disable_if(&mut bits, value.swaps, Self::SWAPS);
+        Self(bits)
+    }
+}
+
+impl From<PalletFeaturesFlags> for PalletFeaturesExt {
+    fn from(value: PalletFeaturesFlags) -> Self {
+        Self {
+            trading: !value.contains(PalletFeaturesFlags::TRADING),
+            attributes: !value.contains(PalletFeaturesFlags::ATTRIBUTES),
+            approvals: !value.contains(PalletFeaturesFlags::APPROVALS),
+            swaps: !value.contains(PalletFeaturesFlags::SWAPS),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Ids of the NFTs chain extension functions.
+//!
+//! A function id is the `u32` passed by the contract to `seal_call_chain_extension`, laid out as
+//! `0xEEEE_VVFF`:
+//!
+//! - `EEEE`, the high 16 bits, is the [`EXTENSION_ID`]. It selects the NFTs extension among the
+//!   other chain extensions of the runtime.
+//! - `VV` is the version of the extension interface the contract was built against.
+//! - `FF` is the function, one of the [`function`] constants.
+//!
+//! Use [`func_id`] to build an id. Version [`LEGACY_VERSION`] is the scheme used before the
+//! extension had an id, when function ids were plain literals (`1` for `create`, `100` for
+//! `get_approvals_limit`...). Those contracts call with an extension id of `0` and are only served
+//! when the NFTs extension is the sole chain extension of the runtime.
+
+/// Id of the NFTs chain extension in the runtime.
+pub const EXTENSION_ID: u16 = 0x4e46;
+
+/// Version of the interface built against by the contracts from this release.
+pub const VERSION: u8 = 1;
+
+/// Version of the function ids used before they were versioned.
+pub const LEGACY_VERSION: u8 = 0;
+
+/// Functions of the extension, the low byte of a function id.
+pub mod function {
+    // Extrinsics
+    pub const CREATE: u8 = 1;
+    pub const REDEPOSIT: u8 = 3;
+    // Privileged extrinsics
+    pub const FORCE_CREATE: u8 = 4;
+    pub const FORCE_MINT: u8 = 5;
+    pub const FORCE_COLLECTION_OWNER: u8 = 6;
+    pub const FORCE_COLLECTION_CONFIG: u8 = 7;
+    pub const FORCE_SET_ATTRIBUTE: u8 = 8;
+    // Chain state
+    pub const GET_COLLECTION: u8 = 2;
+    pub const GET_OWNED_ITEMS: u8 = 9;
+    pub const GET_COLLECTIONS: u8 = 10;
+    pub const GET_OWNED_COLLECTIONS: u8 = 11;
+    pub const GET_COLLECTION_CONFIG: u8 = 12;
+    pub const GET_ITEM_CONFIG: u8 = 13;
+    pub const GET_CAPABILITIES: u8 = 14;
+    // Batches
+    pub const BATCH_MINT: u8 = 15;
+    pub const BATCH_TRANSFER: u8 = 16;
+    // Items
+    pub const TRANSFER: u8 = 17;
+    pub const APPROVE_TRANSFER: u8 = 18;
+    pub const CANCEL_APPROVAL: u8 = 19;
+    pub const GET_ITEM_OWNER: u8 = 20;
+    pub const GET_BALANCE: u8 = 21;
+    pub const GET_APPROVAL: u8 = 22;
+    pub const GET_ATTRIBUTE: u8 = 23;
+    // Constants
+    pub const GET_APPROVALS_LIMIT: u8 = 100;
+    pub const GET_ATTRIBUTE_DEPOSIT_BASE: u8 = 101;
+    pub const GET_COLLECTION_DEPOSIT: u8 = 102;
+    pub const GET_DEPOSIT_PER_BYTE: u8 = 103;
+    pub const GET_NFTS_CONSTANTS: u8 = 104;
+}
+
+/// Functions served in the current [`VERSION`].
+pub const FUNCTIONS: &[u8] = &[
+    function::CREATE,
+    function::GET_COLLECTION,
+    function::REDEPOSIT,
+    function::FORCE_CREATE,
+    function::FORCE_MINT,
+    function::FORCE_COLLECTION_OWNER,
+    function::FORCE_COLLECTION_CONFIG,
+    function::FORCE_SET_ATTRIBUTE,
+    function::GET_OWNED_ITEMS,
+    function::GET_COLLECTIONS,
+    function::GET_OWNED_COLLECTIONS,
+    function::GET_COLLECTION_CONFIG,
+    function::GET_ITEM_CONFIG,
+    function::GET_CAPABILITIES,
+    function::BATCH_MINT,
+    function::BATCH_TRANSFER,
+    function::TRANSFER,
+    function::APPROVE_TRANSFER,
+    function::CANCEL_APPROVAL,
+    function::GET_ITEM_OWNER,
+    function::GET_BALANCE,
+    function::GET_APPROVAL,
+    function::GET_ATTRIBUTE,
+    function::GET_APPROVALS_LIMIT,
+    function::GET_ATTRIBUTE_DEPOSIT_BASE,
+    function::GET_COLLECTION_DEPOSIT,
+    function::GET_DEPOSIT_PER_BYTE,
+    function::GET_NFTS_CONSTANTS,
+];
+
+/// Functions served in the [`LEGACY_VERSION`], the ones of the extension before its ids were
+/// versioned. Any other function of that version is unsupported.
+pub const LEGACY_FUNCTIONS: &[u8] = &[
+    function::CREATE,
+    function::GET_COLLECTION,
+    function::GET_APPROVALS_LIMIT,
+    function::GET_ATTRIBUTE_DEPOSIT_BASE,
+    function::GET_COLLECTION_DEPOSIT,
+    function::GET_DEPOSIT_PER_BYTE,
+];
+
+/// Flag of the function byte requesting a dry run: the function is run and its status and weight
+/// are returned, but all its changes are rolled back. Only the functions dispatching a call of the
+/// pallet can be dry run, in the current [`VERSION`].
+pub const DRY_RUN: u8 = 0x80;
+
+/// Build the id of `function` in the given interface `version`.
+pub const fn func_id(version: u8, function: u8) -> u32 {
+    (EXTENSION_ID as u32) << 16 | (version as u32) << 8 | function as u32
+}
+
+/// Build the id of the dry run of `function` in the given interface `version`.
+pub const fn dry_run_func_id(version: u8, function: u8) -> u32 {
+    func_id(version, function | DRY_RUN)
+}
+
+/// Remove the [`DRY_RUN`] flag from the low 16 bits of a function id, and tell whether it was set.
+pub const fn strip_dry_run(func_id: u16) -> (u16, bool) {
+    (func_id & !(DRY_RUN as u16), func_id & DRY_RUN as u16 != 0)
+}
+
+/// Split the low 16 bits of a function id, as seen by the runtime, into its version and function.
+pub const fn split_func_id(func_id: u16) -> (u8, u8) {
+    ((func_id >> 8) as u8, func_id as u8)
+}
@@ -1,9 +1,31 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod builders;
+pub mod errors;
+pub mod flags;
+pub mod ids;
+
+use alloc::vec::Vec;
 use codec::{Decode, Encode, MaxEncodedLen};
 
+/// Maximum number of items that can be re-evaluated in a single `redeposit` call.
+pub const MAX_REDEPOSIT_ITEMS: u32 = 100;
+
+/// Maximum number of entries returned by a single paginated query.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of entries of a single `batch_mint` or `batch_transfer` call.
+pub const MAX_BATCH_SIZE: u32 = 100;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct CreateInput<AccountId, Price, BlockNumber, CollectionId> {
     pub admin: AccountId,
     pub config: CollectionConfigExt<Price, BlockNumber, CollectionId>, //pub config: CollectionConfigExt<Price, BlockNumber, CollectionId>,
@@ -11,6 +33,11 @@ pub struct CreateInput<AccountId, Price, BlockNumber, CollectionId> {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 /// Mint type. Can the NFT be create by anyone, or only the creator of the collection,
 /// or only by wallets that already hold an NFT from a certain collection?
 /// The ownership of a privately minted NFT is still publicly visible.
@@ -25,6 +52,11 @@ pub enum MintTypeExt<CollectionId> {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub enum Origin {
     Caller,
     Address,
@@ -48,6 +80,30 @@ macro_rules! select_origin {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Attribute namespaces for non-fungible tokens.
+pub enum AttributeNamespaceExt<AccountId> {
+    /// An attribute was set by the pallet.
+    Pallet,
+    /// An attribute was set by collection's owner.
+    CollectionOwner,
+    /// An attribute was set by item's owner.
+    ItemOwner,
+    /// An attribute was set by pre-approved account.
+    Account(AccountId),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct MintSettingsExt<Price, BlockNumber, CollectionId> {
     /// Whether anyone can mint or if minters are restricted to some subset.
     pub mint_type: MintTypeExt<CollectionId>,
@@ -63,6 +119,11 @@ pub struct MintSettingsExt<Price, BlockNumber, CollectionId> {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct ItemSettingsExt {
     /// This item is transferable.
     pub transferable: bool,
@@ -74,6 +135,11 @@ pub struct ItemSettingsExt {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct CollectionSettingsExt {
     /// Items in this collection are transferable.
     pub transferable_items: bool,
@@ -89,6 +155,50 @@ pub struct CollectionSettingsExt {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Configuration of a collection.
+///
+/// With the `serde` feature, the types of this crate are serialized with camel case field and
+/// variant names, and the bit flags as their bits:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use nfts_extension_types::{CollectionConfigExt, MintTypeExt};
+///
+/// let json = r#"{
+///     "setting": {
+///         "transferableItems": true,
+///         "unlockedMetadata": true,
+///         "unlockedAttributes": true,
+///         "unlockedMaxSupply": true,
+///         "depositRequired": true
+///     },
+///     "maxSupply": 1000,
+///     "mintSettings": {
+///         "mintType": { "holderOf": 3 },
+///         "price": null,
+///         "startBlock": null,
+///         "endBlock": null,
+///         "defaultItemSettings": {
+///             "transferable": true,
+///             "unlockedMetadata": true,
+///             "unlockedAttributes": true
+///         }
+///     }
+/// }"#;
+/// let config: CollectionConfigExt<u128, u32, u32> = serde_json::from_str(json).unwrap();
+/// let expected = CollectionConfigExt::builder()
+///     .max_supply(1_000)
+///     .mint_type(MintTypeExt::HolderOf(3))
+///     .build();
+/// assert_eq!(config, expected);
+/// # }
+/// ```
 pub struct CollectionConfigExt<Price, BlockNumber, CollectionId> {
     /// Collection's settings.
     pub setting: CollectionSettingsExt,
@@ -100,6 +210,11 @@ pub struct CollectionConfigExt<Price, BlockNumber, CollectionId> {
 
 #[derive(Debug, Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct CollectionDetailsExt<AccountId, DepositBalance> {
     /// Collection's owner.
     pub owner: AccountId,
@@ -115,3 +230,161 @@ pub struct CollectionDetailsExt<AccountId, DepositBalance> {
     /// The total number of attributes for this collection.
     pub attributes: u32,
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Weight of a call, in its two dimensions.
+pub struct WeightExt {
+    /// Computational time, in picoseconds.
+    pub ref_time: u64,
+    /// Size of the storage proof, in bytes.
+    pub proof_size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Outcome of a dry run.
+pub struct DryRunResultExt {
+    /// Status code the function returned.
+    pub status: u32,
+    /// Weight the function consumed.
+    pub weight: WeightExt,
+    /// Output the function wrote, empty for the functions writing none.
+    pub output: Vec<u8>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// How a batch handles the failure of one of its entries.
+pub enum BatchModeExt {
+    /// The batch stops on the first failure and is rolled back entirely, the error of the failed
+    /// entry is returned.
+    Atomic,
+    /// Every entry is attempted, the status of each one is returned.
+    BestEffort,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Witness data of a mint, required by the `HolderOf` mint type.
+pub struct MintWitnessExt<ItemId> {
+    /// An item of the collection the mint is gated by, owned by the minter.
+    pub owned_item: ItemId,
+}
+
+#[derive(Debug, Clone, Encode, Decode, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// One page of a paginated query.
+pub struct Page<Item, Cursor> {
+    /// Entries of this page.
+    pub items: Vec<Item>,
+    /// Cursor to pass to the next query to continue the enumeration, `None` once it is complete.
+    pub next: Option<Cursor>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct PalletFeaturesExt {
+    /// Enable/disable trading operations.
+    pub trading: bool,
+    /// Allow/disallow setting attributes.
+    pub attributes: bool,
+    /// Allow/disallow transfer approvals.
+    pub approvals: bool,
+    /// Allow/disallow atomic items swap.
+    pub swaps: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Constants of the nfts pallet as configured in the runtime.
+pub struct NftsConstantsExt<Balance, BlockNumber> {
+    /// The basic amount of funds that must be reserved for collection.
+    pub collection_deposit: Balance,
+    /// The basic amount of funds that must be reserved for an item.
+    pub item_deposit: Balance,
+    /// The basic amount of funds that must be reserved when adding metadata to your item.
+    pub metadata_deposit_base: Balance,
+    /// The basic amount of funds that must be reserved when adding an attribute to an item.
+    pub attribute_deposit_base: Balance,
+    /// The additional funds that must be reserved for the number of bytes store in metadata,
+    /// either "normal" metadata or attribute metadata.
+    pub deposit_per_byte: Balance,
+    /// The maximum length of data stored on-chain.
+    pub string_limit: u32,
+    /// The maximum length of an attribute key.
+    pub key_limit: u32,
+    /// The maximum length of an attribute value.
+    pub value_limit: u32,
+    /// The maximum approvals an item could have.
+    pub approvals_limit: u32,
+    /// The maximum attributes approvals an item could have.
+    pub item_attributes_approvals_limit: u32,
+    /// The max number of tips a user could send.
+    pub max_tips: u32,
+    /// The max duration in blocks for deadlines.
+    pub max_deadline_duration: BlockNumber,
+    /// The max number of attributes a user could set per call.
+    pub max_attributes_per_call: u32,
+    /// The pallet features enabled in the runtime.
+    pub features: PalletFeaturesExt,
+}
+
+#[derive(Debug, Clone, Encode, Decode, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// What the extension of the runtime supports, for contracts to check before calling it.
+pub struct CapabilitiesExt {
+    /// Interface version of the extension, see `ids::VERSION`.
+    pub version: u8,
+    /// Ids of the functions served by the extension, as built by `ids::func_id`.
+    pub functions: Vec<u32>,
+    /// The pallet features enabled in the runtime.
+    pub features: PalletFeaturesExt,
+}
+
+impl CapabilitiesExt {
+    /// Whether the function of the given id is served by the extension.
+    pub fn supports(&self, func_id: u32) -> bool {
+        self.functions.contains(&func_id)
+    }
+}